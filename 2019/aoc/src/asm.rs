@@ -0,0 +1,471 @@
+use crate::intcode::{Instruction, Mode, Op};
+use aoc_common::Diagnostic;
+use std::collections::{BTreeSet, HashMap};
+use std::fmt::Write;
+
+// values per line when listing data
+const DATA_WIDTH: usize = 8;
+
+#[derive(Debug)]
+pub enum Error {
+  Parse(Diagnostic),
+}
+
+const OPS: [Op; 10] = [
+  Op::Add,
+  Op::Multiply,
+  Op::Input,
+  Op::Output,
+  Op::JumpIfTrue,
+  Op::JumpIfFalse,
+  Op::LessThan,
+  Op::Equals,
+  Op::AdjustBase,
+  Op::Halt,
+];
+
+pub fn mnemonic(op: Op) -> &'static str {
+  match op {
+    Op::Add => "add",
+    Op::Multiply => "mul",
+    Op::Input => "in",
+    Op::Output => "out",
+    Op::JumpIfTrue => "jt",
+    Op::JumpIfFalse => "jf",
+    Op::LessThan => "lt",
+    Op::Equals => "eq",
+    Op::AdjustBase => "arb",
+    Op::Halt => "hlt",
+  }
+}
+
+pub fn op_named(name: &str) -> Option<Op> {
+  OPS.into_iter().find(|&op| mnemonic(op) == name)
+}
+
+fn is_jump(op: Op) -> bool {
+  op == Op::JumpIfTrue || op == Op::JumpIfFalse
+}
+
+// the instructions that may run, found by following the program from
+// address 0. Jumps to computed addresses can't be followed, so code only
+// reached through them is taken for data. Instructions that would overlap
+// one already found, or run off the end of memory, are left out too.
+pub fn reachable(memory: &[i64]) -> BTreeSet<usize> {
+  let mut starts = BTreeSet::new();
+  let mut claimed = vec![false; memory.len()];
+  let mut pending = vec![0];
+
+  while let Some(address) = pending.pop() {
+    if address >= memory.len() || starts.contains(&address) {
+      continue;
+    }
+    let Ok(instruction) = Instruction::decode(memory[address]) else {
+      continue;
+    };
+    let end = address + 1 + instruction.op.arity();
+    if end > memory.len() || claimed[address..end].contains(&true) {
+      continue;
+    }
+    claimed[address..end].fill(true);
+    starts.insert(address);
+
+    let mut falls_through = instruction.op != Op::Halt;
+    if is_jump(instruction.op) {
+      if instruction.modes[1] == Mode::Immediate {
+        if let Ok(target) = usize::try_from(memory[address + 2]) {
+          pending.push(target);
+        }
+      }
+      // an immediate condition always jumps or never does
+      if instruction.modes[0] == Mode::Immediate {
+        let condition = memory[address + 1] != 0;
+        falls_through = condition != (instruction.op == Op::JumpIfTrue);
+      }
+    }
+    if falls_through {
+      pending.push(end);
+    }
+  }
+
+  starts
+}
+
+fn operand(mode: Mode, value: i64) -> String {
+  match mode {
+    Mode::Position => format!("[{}]", value),
+    Mode::Immediate => value.to_string(),
+    Mode::Relative if value < 0 => format!("[rb-{}]", value.unsigned_abs()),
+    Mode::Relative => format!("[rb+{}]", value),
+  }
+}
+
+// the instruction at `address` in assembler syntax, if there is a whole one
+pub fn instruction_at(memory: &[i64], address: usize) -> Option<String> {
+  let instruction = Instruction::decode(*memory.get(address)?).ok()?;
+  let params = memory.get(address + 1..address + 1 + instruction.op.arity())?;
+  Some(format_instruction(&instruction, params, &HashMap::new()))
+}
+
+fn format_instruction(
+  instruction: &Instruction,
+  params: &[i64],
+  labels: &HashMap<i64, String>,
+) -> String {
+  let operands: Vec<String> = params
+    .iter()
+    .zip(instruction.modes)
+    .enumerate()
+    .map(|(n, (&value, mode))| match labels.get(&value) {
+      Some(label) if n == 1 && is_jump(instruction.op) && mode == Mode::Immediate => label.clone(),
+      _ => operand(mode, value),
+    })
+    .collect();
+
+  let mnemonic = mnemonic(instruction.op);
+  if operands.is_empty() {
+    mnemonic.to_string()
+  } else {
+    format!("{} {}", mnemonic, operands.join(", "))
+  }
+}
+
+// a listing of the whole memory image, one instruction or run of data per
+// line, each starting with its address. Jump targets get labels.
+// `assemble` turns it back into the same image.
+pub fn disassemble(memory: &[i64]) -> String {
+  let starts = reachable(memory);
+  let mut labels = HashMap::new();
+  for &address in &starts {
+    let instruction = Instruction::decode(memory[address]).expect("decoded when found");
+    let target = memory.get(address + 2).copied().unwrap_or(-1);
+    if is_jump(instruction.op)
+      && instruction.modes[1] == Mode::Immediate
+      && usize::try_from(target).is_ok_and(|t| starts.contains(&t))
+    {
+      labels.insert(target, format!("l{}", target));
+    }
+  }
+
+  let mut listing = String::new();
+  let mut address = 0;
+  while address < memory.len() {
+    if starts.contains(&address) {
+      let instruction = Instruction::decode(memory[address]).expect("decoded when found");
+      let end = address + 1 + instruction.op.arity();
+      if let Some(label) = labels.get(&(address as i64)) {
+        writeln!(listing, "{}:", label).unwrap();
+      }
+      let text = format_instruction(&instruction, &memory[address + 1..end], &labels);
+      writeln!(listing, "{:04}: {}", address, text).unwrap();
+      address = end;
+    } else {
+      let end = (address + 1..memory.len())
+        .take(DATA_WIDTH - 1)
+        .find(|a| starts.contains(a))
+        .unwrap_or_else(|| (address + DATA_WIDTH).min(memory.len()));
+      let values: Vec<String> = memory[address..end].iter().map(i64::to_string).collect();
+      writeln!(listing, "{:04}: data {}", address, values.join(", ")).unwrap();
+      address = end;
+    }
+  }
+
+  listing
+}
+
+enum Value<'a> {
+  Number(i64),
+  Label(&'a str),
+}
+
+enum Item<'a> {
+  Instruction(Op, Vec<(Mode, Value<'a>, &'a str)>),
+  Data(Vec<(Value<'a>, &'a str)>),
+}
+
+fn is_label(name: &str) -> bool {
+  let mut chars = name.chars();
+  chars
+    .next()
+    .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+    && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+// turns a listing back into a memory image. Each line is an optional
+// label, an optional address, and an instruction or data:
+//
+//   loop:
+//   0004: add [rb+1], 7, [20]   ; comments run to the end of the line
+//   data 1, -2, loop
+//
+// Parameters are immediate, in brackets for position mode, or rb+n in
+// brackets for relative mode. Labels may be used wherever a number can. An
+// address must match where the line ends up.
+pub fn assemble<'a>(path: &str, text: &'a str) -> Result<Vec<i64>, Error> {
+  let mut labels: HashMap<&'a str, i64> = HashMap::new();
+  let mut items = Vec::new();
+  let mut address = 0;
+
+  for (idx, raw_line) in text.lines().enumerate() {
+    let diagnostic = |token: &str, message: String| {
+      Error::Parse(Diagnostic::new(path, idx + 1, raw_line, token, message))
+    };
+    let mut line = raw_line.split(';').next().unwrap_or("").trim();
+
+    while let Some((prefix, rest)) = line.split_once(':') {
+      let prefix = prefix.trim();
+      if prefix.chars().all(|c| c.is_ascii_digit()) && !prefix.is_empty() {
+        if prefix.parse::<usize>().ok() != Some(address) {
+          return Err(diagnostic(
+            prefix,
+            format!("this line is at address {}", address),
+          ));
+        }
+      } else if is_label(prefix) {
+        if labels.insert(prefix, address as i64).is_some() {
+          return Err(diagnostic(prefix, format!("{} is already defined", prefix)));
+        }
+      } else {
+        return Err(diagnostic(
+          prefix,
+          "expected a label or address".to_string(),
+        ));
+      }
+      line = rest.trim();
+    }
+    if line.is_empty() {
+      continue;
+    }
+
+    let (name, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+    let operands: Vec<&str> = match rest.trim() {
+      "" => Vec::new(),
+      rest => rest.split(',').map(str::trim).collect(),
+    };
+    let value = |token: &'a str| match token.parse::<i64>() {
+      Ok(n) => Ok(Value::Number(n)),
+      Err(_) if is_label(token) => Ok(Value::Label(token)),
+      Err(_) => Err(diagnostic(token, "expected a number or label".to_string())),
+    };
+
+    if name == "data" {
+      let values = operands
+        .iter()
+        .map(|&token| Ok((value(token)?, token)))
+        .collect::<Result<Vec<_>, Error>>()?;
+      address += values.len();
+      items.push((idx, Item::Data(values)));
+      continue;
+    }
+
+    let op =
+      op_named(name).ok_or_else(|| diagnostic(name, format!("unknown instruction {}", name)))?;
+    if operands.len() != op.arity() {
+      return Err(diagnostic(
+        line,
+        format!("{} takes {} parameters", name, op.arity()),
+      ));
+    }
+
+    let params = operands
+      .iter()
+      .map(|&token| {
+        let Some(inner) = token.strip_prefix('[').and_then(|t| t.strip_suffix(']')) else {
+          return Ok((Mode::Immediate, value(token)?, token));
+        };
+        let inner = inner.trim();
+        match inner.strip_prefix("rb") {
+          Some(offset) => match offset.trim_start() {
+            "" => Ok((Mode::Relative, Value::Number(0), token)),
+            offset if offset.starts_with('+') => {
+              Ok((Mode::Relative, value(offset[1..].trim())?, token))
+            }
+            offset if offset.starts_with('-') => match format!("-{}", offset[1..].trim()).parse() {
+              Ok(n) => Ok((Mode::Relative, Value::Number(n), token)),
+              Err(_) => Err(diagnostic(token, "expected rb-<number>".to_string())),
+            },
+            _ => Ok((Mode::Position, value(inner)?, token)),
+          },
+          None => Ok((Mode::Position, value(inner)?, token)),
+        }
+      })
+      .collect::<Result<Vec<_>, Error>>()?;
+    address += 1 + params.len();
+    items.push((idx, Item::Instruction(op, params)));
+  }
+
+  let mut memory = Vec::with_capacity(address);
+  for (idx, item) in items {
+    let resolve = |value: &Value, token: &str| match *value {
+      Value::Number(n) => Ok(n),
+      Value::Label(label) => labels.get(label).copied().ok_or_else(|| {
+        let line = text.lines().nth(idx).unwrap_or("");
+        Error::Parse(Diagnostic::new(
+          path,
+          idx + 1,
+          line,
+          token,
+          format!("no label {}", label),
+        ))
+      }),
+    };
+
+    match item {
+      Item::Data(values) => {
+        for (value, token) in values {
+          memory.push(resolve(&value, token)?);
+        }
+      }
+      Item::Instruction(op, params) => {
+        let mut modes = [Mode::Position; 3];
+        for (n, (mode, _, _)) in params.iter().enumerate() {
+          modes[n] = *mode;
+        }
+        memory.push(Instruction { op, modes }.encode());
+        for (_, value, token) in params {
+          memory.push(resolve(&value, token)?);
+        }
+      }
+    }
+  }
+
+  Ok(memory)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{assemble, disassemble, instruction_at, reachable, Error};
+
+  #[test]
+  fn test_disassemble() {
+    let program = [1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+    assert_eq!(
+      disassemble(&program),
+      "0000: add [9], [10], [3]\n\
+       0004: mul [3], [11], [0]\n\
+       0008: hlt\n\
+       0009: data 30, 40, 50\n"
+    );
+
+    // reads until it's given 0, with a loop and an unreachable output
+    let program = [
+      3, 20, 1006, 20, 9, 1105, 1, 0, 104, 99, 109, -3, 22201, -1, 0, 4,
+    ];
+    assert_eq!(
+      disassemble(&program),
+      "l0:\n\
+       0000: in [20]\n\
+       0002: jf [20], l9\n\
+       0005: jt 1, l0\n\
+       0008: data 104\n\
+       l9:\n\
+       0009: hlt\n\
+       0010: data 109, -3, 22201, -1, 0, 4\n"
+    );
+    assert_eq!(
+      instruction_at(&program, 12).as_deref(),
+      Some("add [rb-1], [rb+0], [rb+4]")
+    );
+    assert_eq!(instruction_at(&program, 15), None);
+    assert_eq!(
+      reachable(&program).into_iter().collect::<Vec<_>>(),
+      vec![0, 2, 5, 9]
+    );
+  }
+
+  #[test]
+  fn test_assemble() {
+    let source = "
+      start:
+        in [count]        ; how many times round
+      loop:
+        jf [count], done
+        out [rb-2]
+        add [count], -1, [count]
+        arb 1
+        jt 1, loop
+      done: hlt
+      count: data 0
+    ";
+    assert_eq!(
+      assemble("loop.s", source).unwrap(),
+      vec![3, 17, 1006, 17, 16, 204, -2, 1001, 17, -1, 17, 109, 1, 1105, 1, 2, 99, 0]
+    );
+
+    let error = |source: &str| match assemble("bad.s", source) {
+      Err(Error::Parse(d)) => (d.line, d.column, d.message),
+      other => panic!("unexpected {:?}", other),
+    };
+    assert_eq!(
+      error("hlt\nfoo 1"),
+      (2, 1, "unknown instruction foo".to_string())
+    );
+    assert_eq!(
+      error("add 1, 2"),
+      (1, 1, "add takes 3 parameters".to_string())
+    );
+    assert_eq!(
+      error("jt 1, nowhere"),
+      (1, 7, "no label nowhere".to_string())
+    );
+    assert_eq!(
+      error("0000: hlt\n0002: hlt"),
+      (2, 1, "this line is at address 1".to_string())
+    );
+    assert_eq!(
+      error("a: hlt\na: hlt"),
+      (2, 1, "a is already defined".to_string())
+    );
+    assert_eq!(
+      error("out [rb-x]"),
+      (1, 5, "expected rb-<number>".to_string())
+    );
+    assert_eq!(
+      error("data 1, 2x"),
+      (1, 9, "expected a number or label".to_string())
+    );
+  }
+
+  #[test]
+  fn test_round_trip() {
+    let mut programs = vec![
+      vec![],
+      vec![1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50],
+      // a jump into the middle of an instruction, and one off the end
+      vec![1105, 1, 4, 1101, 99, 0, 7, 1106, 0, 100],
+      vec![
+        109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+      ],
+      vec![i64::MIN, i64::MAX, 10001, 21101, 1, 2, 3, 99],
+      vec![204, i64::MIN, 2201, i64::MAX, 0, -1, 99],
+    ];
+
+    // and some noise, mostly made of plausible instructions
+    let pieces = [
+      1, 2, 99, 1105, 1106, 204, 21101, 1002, 3, 4, 9, 0, 5, -7, 123456,
+    ];
+    let mut seed: u64 = 2019;
+    for len in 1..200 {
+      programs.push(
+        (0..len)
+          .map(|_| {
+            seed = seed
+              .wrapping_mul(6364136223846793005)
+              .wrapping_add(1442695040888963407);
+            pieces[(seed >> 33) as usize % pieces.len()]
+          })
+          .collect(),
+      );
+    }
+
+    for program in programs {
+      let listing = disassemble(&program);
+      assert_eq!(
+        assemble("listing.s", &listing).unwrap(),
+        program,
+        "\n{}",
+        listing
+      );
+    }
+  }
+}
@@ -0,0 +1,36 @@
+use aoc2019::{asm, debugger};
+use aoc_common::{input, Source};
+use std::io;
+use std::process::ExitCode;
+
+// debugs an Intcode program, given as comma separated values like the
+// puzzle inputs or as an assembler listing ending in .s
+fn main() -> ExitCode {
+    let Some(path) = std::env::args().nth(1) else {
+        eprintln!("usage: intcode <program.txt|listing.s>");
+        return ExitCode::FAILURE;
+    };
+
+    let program = if path.ends_with(".s") {
+        input::read_to_string(Source::file(&path))
+            .map_err(|e| e.to_string())
+            .and_then(|text| asm::assemble(&path, &text).map_err(|e| format!("{:?}", e)))
+    } else {
+        input::parse_comma_separated_ints::<i64>(Source::file(&path)).map_err(|e| e.to_string())
+    };
+    let program = match program {
+        Ok(program) => program,
+        Err(e) => {
+            eprintln!("cannot load {}: {}", path, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match debugger::repl(&program, io::stdin().lock(), io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
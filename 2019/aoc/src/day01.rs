@@ -1,15 +1,28 @@
-use super::util;
+use aoc_common::{input, Solution, Source};
 
-pub fn part01(path: &str) -> Result<i64, util::Error> {
-  let masses = util::parse_int_per_line(path)?;
-  Ok(masses.iter().fold(0, |s, &m| s + fuel_required(m)))
-}
+pub struct Day01;
+
+impl Solution for Day01 {
+  type Input = Vec<i64>;
+  type Config = ();
+  type PartOne = i64;
+  type PartTwo = i64;
+  type Error = aoc_common::Error;
+
+  fn parse(source: Source) -> Result<Vec<i64>, aoc_common::Error> {
+    input::parse_int_per_line::<i64>(source)
+  }
 
-pub fn part02(path: &str) -> Result<i64, util::Error> {
-  let masses = util::parse_int_per_line(path)?;
-  Ok(masses.iter().fold(0, |s, &m| s + fuel_required_part2(m)))
+  fn part_one(masses: &Vec<i64>, _: &()) -> Result<i64, aoc_common::Error> {
+    Ok(masses.iter().fold(0, |s, &m| s + fuel_required(m)))
+  }
+
+  fn part_two(masses: &Vec<i64>, _: &()) -> Result<i64, aoc_common::Error> {
+    Ok(masses.iter().fold(0, |s, &m| s + fuel_required_part2(m)))
+  }
 }
 
+
 fn fuel_required(mass: i64) -> i64 {
   (mass / 3) - 2
 }
@@ -1,25 +1,69 @@
-use super::util;
+use crate::intcode::{self, Intcode};
+use aoc_common::{input, Diagnostic, Solution, Source};
 
 #[derive(Debug)]
 pub enum Error {
-  InvalidOpcode(i64),
-  Util(util::Error),
+  Intcode(intcode::Error),
+  Input(aoc_common::Error),
+  // the program is too short to have a noun and verb
+  ParseProgram(Diagnostic),
   SolutionNotFound,
 }
 
-pub fn part01(path: &str) -> Result<i64, Error> {
-  let mut input = util::parse_comma_separated_ints(path).map_err(Error::Util)?;
-  // To do this, before running the program, replace position 1 with the value 12 and replace position 2 with the value 2.
-  input[1] = 12;
-  input[2] = 2;
+pub struct Day02;
 
-  let output = run_program(&input)?;
+impl Solution for Day02 {
+  type Input = Vec<i64>;
+  type Config = ();
+  type PartOne = i64;
+  type PartTwo = i64;
+  type Error = Error;
 
-  Ok(output[0])
+  fn parse(source: Source) -> Result<Vec<i64>, Error> {
+    let path = source.name().to_string();
+    let contents = input::read_to_string(source).map_err(Error::Input)?;
+    let program =
+      input::parse_comma_separated_ints::<i64>(Source::text(contents.as_str()).named(&path))
+        .map_err(Error::Input)?;
+
+    if program.len() < 3 {
+      let (idx, line) = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .last()
+        .unwrap_or((0, ""));
+      let message = format!("expected at least 3 values, found {}", program.len());
+      return Err(Error::ParseProgram(Diagnostic::new(
+        &path,
+        idx + 1,
+        line,
+        line,
+        message,
+      )));
+    }
+    Ok(program)
+  }
+
+  fn part_one(input: &Vec<i64>, _: &()) -> Result<i64, Error> {
+    let mut program = input.clone();
+    // To do this, before running the program, replace position 1 with the value 12 and replace position 2 with the value 2.
+    program[1] = 12;
+    program[2] = 2;
+
+    let output = run_program(&program)?;
+
+    Ok(output[0])
+  }
+
+  fn part_two(input: &Vec<i64>, _: &()) -> Result<i64, Error> {
+    let (noun, verb) = find_noun_verb(input)?;
+
+    Ok(100 * noun + verb)
+  }
 }
-pub fn part02(path: &str) -> Result<(i64, i64), Error> {
-  let input = util::parse_comma_separated_ints(path).map_err(Error::Util)?;
 
+pub fn find_noun_verb(input: &Vec<i64>) -> Result<(i64, i64), Error> {
   for i in 0..=99 {
     for j in 0..=99 {
       let mut program = input.clone();
@@ -37,31 +81,33 @@ pub fn part02(path: &str) -> Result<(i64, i64), Error> {
 }
 
 fn run_program(input: &Vec<i64>) -> Result<Vec<i64>, Error> {
-  let mut program = input.clone();
-  let len = program.len();
+  let mut machine = Intcode::new(input);
+  machine.run().map_err(Error::Intcode)?;
 
-  for i in (0..len).step_by(4) {
-    let opcode = program[i];
-    if opcode == 99 {
-      break;
-    }
-    let input_one_idx = program[i + 1] as usize;
-    let input_two_idx = program[i + 2] as usize;
-    let output_idx = program[i + 3] as usize;
-
-    program[output_idx] = match opcode {
-      1 => program[input_one_idx] + program[input_two_idx],
-      2 => program[input_one_idx] * program[input_two_idx],
-      _ => return Err(Error::InvalidOpcode(opcode)),
-    };
-  }
-
-  Ok(program)
+  Ok(machine.memory().to_vec())
 }
 
 #[cfg(test)]
 mod tests {
-  use super::run_program;
+  use super::{run_program, Day02, Error};
+  use aoc_common::{Solution, Source};
+
+  #[test]
+  fn test_parse() {
+    assert_eq!(
+      Day02::parse(Source::text("1,0,0,99\n")).unwrap(),
+      vec![1, 0, 0, 99]
+    );
+    match Day02::parse(Source::text("\n1,0\n")) {
+      Err(Error::ParseProgram(d)) => assert_eq!((d.line, d.snippet.as_str()), (2, "1,0")),
+      other => panic!("unexpected {:?}", other),
+    }
+    assert!(matches!(
+      Day02::parse(Source::text("")),
+      Err(Error::ParseProgram(_))
+    ));
+  }
+
   #[test]
   fn test_run_program() {
     assert_eq!(
@@ -1,10 +1,10 @@
+use aoc_common::{Diagnostic, Solution, Source};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{self, BufRead};
+use std::io::BufRead;
 
 #[derive(Debug, Clone)]
-enum Segment {
+pub enum Segment {
   Right(usize),
   Left(usize),
   Up(usize),
@@ -24,22 +24,33 @@ impl Segment {
 
 #[derive(Debug)]
 pub enum Error {
-  ParseInt(std::num::ParseIntError),
-  ParseSegment(String),
+  ParseSegment(Diagnostic),
+  // the input should be two wires, one per line
+  WireCount(Diagnostic),
   IO(std::io::Error),
   NoIntersections,
 }
 
-pub fn part01(path: &str) -> Result<i64, Error> {
-  let (wire1, wire2) = open_file(path)?;
+pub struct Day03;
 
-  closest_intersect(&wire1, &wire2)
-}
+impl Solution for Day03 {
+  type Input = (Vec<Segment>, Vec<Segment>);
+  type Config = ();
+  type PartOne = i64;
+  type PartTwo = i64;
+  type Error = Error;
 
-pub fn part02(path: &str) -> Result<i64, Error> {
-  let (wire1, wire2) = open_file(path)?;
+  fn parse(source: Source) -> Result<(Vec<Segment>, Vec<Segment>), Error> {
+    read_wires(source)
+  }
 
-  quickest_intersect(&wire1, &wire2)
+  fn part_one((wire1, wire2): &(Vec<Segment>, Vec<Segment>), _: &()) -> Result<i64, Error> {
+    closest_intersect(wire1, wire2)
+  }
+
+  fn part_two((wire1, wire2): &(Vec<Segment>, Vec<Segment>), _: &()) -> Result<i64, Error> {
+    quickest_intersect(wire1, wire2)
+  }
 }
 
 fn closest_intersect(wire1: &Vec<Segment>, wire2: &Vec<Segment>) -> Result<i64, Error> {
@@ -119,50 +130,82 @@ fn quickest_intersect(wire1: &Vec<Segment>, wire2: &Vec<Segment>) -> Result<i64,
   quickest_path_to_intersection.ok_or(Error::NoIntersections)
 }
 
-fn open_file(path: &str) -> Result<(Vec<Segment>, Vec<Segment>), Error> {
-  let lines: Vec<Vec<Segment>> = io::BufReader::new(File::open(path).map_err(Error::IO)?)
+fn read_wires(source: Source) -> Result<(Vec<Segment>, Vec<Segment>), Error> {
+  let path = source.name().to_string();
+  let lines = source
     .lines()
-    .map(|maybe_line| {
-      let line = maybe_line.map_err(Error::IO)?;
-      parse_line(&line)
+    .collect::<Result<Vec<String>, _>>()
+    .map_err(Error::IO)?;
+  let wires: Vec<Vec<Segment>> = lines
+    .iter()
+    .enumerate()
+    .map(|(idx, line)| {
+      parse_line(line).map_err(|e| match e {
+        Error::ParseSegment(diagnostic) => Error::ParseSegment(Diagnostic {
+          path: path.clone(),
+          line: idx + 1,
+          ..diagnostic
+        }),
+        e => e,
+      })
     })
-    .into_iter()
     .collect::<Result<Vec<Vec<Segment>>, Error>>()?;
 
-  Ok((lines[0].clone(), lines[1].clone()))
+  match wires.as_slice() {
+    [wire1, wire2] => Ok((wire1.clone(), wire2.clone())),
+    _ => {
+      // the missing line, or the first one too many
+      let idx = lines.len().min(2);
+      let line = lines.get(idx).map_or("", String::as_str);
+      let message = format!("expected 2 wires, found {}", wires.len());
+      Err(Error::WireCount(Diagnostic::new(
+        &path,
+        idx + 1,
+        line,
+        line,
+        message,
+      )))
+    }
+  }
 }
 
+// errors point at line 1 of an unnamed input; read_wires fills in the real location
 fn parse_line(line: &str) -> Result<Vec<Segment>, Error> {
+  let diagnostic = |token: &str, message: &str| {
+    Error::ParseSegment(Diagnostic::new("<input>", 1, line, token, message))
+  };
+
   line
     .split(",")
     .map(|s| {
+      let distance = || {
+        s[1..]
+          .parse::<usize>()
+          .map_err(|e| diagnostic(&s[1..], &e.to_string()))
+      };
+
       if s.starts_with("R") {
-        Ok(Segment::Right(
-          s[1..].parse::<usize>().map_err(Error::ParseInt)?,
-        ))
+        Ok(Segment::Right(distance()?))
       } else if s.starts_with("L") {
-        Ok(Segment::Left(
-          s[1..].parse::<usize>().map_err(Error::ParseInt)?,
-        ))
+        Ok(Segment::Left(distance()?))
       } else if s.starts_with("U") {
-        Ok(Segment::Up(
-          s[1..].parse::<usize>().map_err(Error::ParseInt)?,
-        ))
+        Ok(Segment::Up(distance()?))
       } else if s.starts_with("D") {
-        Ok(Segment::Down(
-          s[1..].parse::<usize>().map_err(Error::ParseInt)?,
-        ))
+        Ok(Segment::Down(distance()?))
       } else {
-        Err(Error::ParseSegment(s.to_string()))
+        Err(diagnostic(s, "expected a segment like \"R8\""))
       }
     })
     .collect::<Result<Vec<Segment>, Error>>()
 }
 
+#[cfg(test)]
 mod tests {
   use super::closest_intersect;
   use super::parse_line;
   use super::quickest_intersect;
+  use super::Day03;
+  use aoc_common::{Solution, Source};
 
   #[test]
   fn test_closest_intersect1() {
@@ -205,4 +248,25 @@ mod tests {
     let wire2 = parse_line("U98,R91,D20,R16,D67,R40,U7,R15,U6,R7").unwrap();
     assert_eq!(quickest_intersect(&wire1, &wire2).unwrap(), 410);
   }
+
+  #[test]
+  fn test_parse_text() {
+    let wires = Day03::parse(Source::text("R8,U5,L5,D3\nU7,R6,D4,L4\n")).unwrap();
+    assert_eq!(Day03::part_one(&wires, &()).unwrap(), 6);
+    assert_eq!(Day03::part_two(&wires, &()).unwrap(), 30);
+
+    match Day03::parse(Source::text("R8,U5\nU7,X6\n")) {
+      Err(super::Error::ParseSegment(diagnostic)) => {
+        assert_eq!((diagnostic.path.as_str(), diagnostic.line), ("<input>", 2))
+      }
+      other => panic!("expected a parse error, got {:?}", other),
+    }
+
+    for (text, line) in [("R8,U5\n", 2), ("R8\nU5\nL5\n", 3), ("", 1)] {
+      match Day03::parse(Source::text(text)) {
+        Err(super::Error::WireCount(diagnostic)) => assert_eq!(diagnostic.line, line),
+        other => panic!("{:?}: expected a wire count error, got {:?}", text, other),
+      }
+    }
+  }
 }
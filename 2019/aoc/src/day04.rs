@@ -1,7 +1,54 @@
-pub fn part01() -> i64 {
-  let range_low = 153517;
-  let range_high = 630395;
+use aoc_common::{input, Diagnostic, Solution, Source};
 
+#[derive(Debug)]
+pub enum Error {
+  Input(aoc_common::Error),
+  ParseRange(Diagnostic),
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+  type Input = (usize, usize);
+  type Config = ();
+  type PartOne = i64;
+  type PartTwo = i64;
+  type Error = Error;
+
+  fn parse(source: Source) -> Result<(usize, usize), Error> {
+    let path = source.name().to_string();
+    let contents = input::read_to_string(source).map_err(Error::Input)?;
+    let (idx, text) = contents
+      .lines()
+      .enumerate()
+      .find(|(_, text)| !text.trim().is_empty())
+      .unwrap_or((0, ""));
+    let line = text.trim();
+    let diagnostic = |token: &str, message: &str| {
+      Error::ParseRange(Diagnostic::new(&path, idx + 1, text, token, message))
+    };
+
+    let (low, high) = line
+      .split_once("-")
+      .ok_or_else(|| diagnostic(line, "expected \"<low>-<high>\""))?;
+    let parse_bound = |s: &str| {
+      s.parse::<usize>()
+        .map_err(|e| diagnostic(s, &e.to_string()))
+    };
+
+    Ok((parse_bound(low)?, parse_bound(high)?))
+  }
+
+  fn part_one(&(range_low, range_high): &(usize, usize), _: &()) -> Result<i64, Error> {
+    Ok(count_passwords(range_low, range_high))
+  }
+
+  fn part_two(&(range_low, range_high): &(usize, usize), _: &()) -> Result<i64, Error> {
+    Ok(count_passwords_strict(range_low, range_high))
+  }
+}
+
+fn count_passwords(range_low: usize, range_high: usize) -> i64 {
   let mut count = 0;
   for n in range_low..=range_high {
     let mut nn = n;
@@ -29,10 +76,7 @@ pub fn part01() -> i64 {
   count
 }
 
-pub fn part02() -> i64 {
-  let range_low = 153517;
-  let range_high = 630395;
-
+fn count_passwords_strict(range_low: usize, range_high: usize) -> i64 {
   let mut count = 0;
   for n in range_low..=range_high {
     let mut nn = n;
@@ -62,3 +106,22 @@ pub fn part02() -> i64 {
 
   count
 }
+
+#[cfg(test)]
+mod tests {
+  use super::{Day04, Error};
+  use aoc_common::{Solution, Source};
+
+  #[test]
+  fn test_parse() {
+    assert_eq!(
+      Day04::parse(Source::text("\n 100-200\n")).unwrap(),
+      (100, 200)
+    );
+
+    match Day04::parse(Source::text("\n\n  100-2x0\n")) {
+      Err(Error::ParseRange(d)) => assert_eq!((d.line, d.column), (3, 7)),
+      other => panic!("unexpected {:?}", other),
+    }
+  }
+}
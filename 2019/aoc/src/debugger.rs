@@ -0,0 +1,551 @@
+use crate::asm;
+use crate::intcode::{self, Event, Instruction, Intcode, Op};
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::{self, BufRead, BufWriter, Write};
+
+#[derive(Debug)]
+pub enum Error {
+  Machine(intcode::Error),
+  Trace(io::Error),
+}
+
+// why the debugger handed control back
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stop {
+  // about to execute the instruction at this address
+  Breakpoint(usize),
+  Watchpoint { address: usize, old: i64, new: i64 },
+  NeedsInput,
+  Halted,
+  // a single step with nothing else to report
+  Stepped,
+}
+
+// runs a machine an instruction at a time, stopping at breakpoints on
+// addresses or ops, and when a watched cell of memory changes
+pub struct Debugger {
+  machine: Intcode,
+  breakpoints: BTreeSet<usize>,
+  op_breakpoints: Vec<Op>,
+  watchpoints: BTreeSet<usize>,
+  trace: Option<Box<dyn Write>>,
+  outputs: Vec<i64>,
+  steps: usize,
+}
+
+impl Debugger {
+  pub fn new(machine: Intcode) -> Debugger {
+    Debugger {
+      machine,
+      breakpoints: BTreeSet::new(),
+      op_breakpoints: Vec::new(),
+      watchpoints: BTreeSet::new(),
+      trace: None,
+      outputs: Vec::new(),
+      steps: 0,
+    }
+  }
+
+  pub fn machine(&self) -> &Intcode {
+    &self.machine
+  }
+
+  pub fn machine_mut(&mut self) -> &mut Intcode {
+    &mut self.machine
+  }
+
+  // starts again with another machine, keeping breakpoints, watchpoints
+  // and the trace
+  pub fn reset(&mut self, machine: Intcode) {
+    self.machine = machine;
+    self.outputs.clear();
+    self.steps = 0;
+  }
+
+  // instructions executed so far
+  pub fn steps(&self) -> usize {
+    self.steps
+  }
+
+  // everything output since the last call
+  pub fn take_outputs(&mut self) -> Vec<i64> {
+    std::mem::take(&mut self.outputs)
+  }
+
+  // returns false if the breakpoint was already set
+  pub fn break_at(&mut self, address: usize) -> bool {
+    self.breakpoints.insert(address)
+  }
+
+  pub fn break_on(&mut self, op: Op) -> bool {
+    if self.op_breakpoints.contains(&op) {
+      return false;
+    }
+    self.op_breakpoints.push(op);
+    true
+  }
+
+  pub fn watch(&mut self, address: usize) -> bool {
+    self.watchpoints.insert(address)
+  }
+
+  // each returns false if there was nothing to remove
+  pub fn clear_break_at(&mut self, address: usize) -> bool {
+    self.breakpoints.remove(&address)
+  }
+
+  pub fn clear_break_on(&mut self, op: Op) -> bool {
+    let before = self.op_breakpoints.len();
+    self.op_breakpoints.retain(|&o| o != op);
+    self.op_breakpoints.len() != before
+  }
+
+  pub fn unwatch(&mut self, address: usize) -> bool {
+    self.watchpoints.remove(&address)
+  }
+
+  pub fn breakpoints(&self) -> impl Iterator<Item = &usize> {
+    self.breakpoints.iter()
+  }
+
+  pub fn op_breakpoints(&self) -> &[Op] {
+    &self.op_breakpoints
+  }
+
+  pub fn watchpoints(&self) -> impl Iterator<Item = &usize> {
+    self.watchpoints.iter()
+  }
+
+  // logs every instruction executed from now on, or stops logging
+  pub fn trace_to(&mut self, trace: Option<Box<dyn Write>>) -> io::Result<()> {
+    if let Some(mut old) = self.trace.take() {
+      old.flush()?;
+    }
+    self.trace = trace;
+    Ok(())
+  }
+
+  fn breaks_here(&self) -> bool {
+    let ip = self.machine.ip();
+    self.breakpoints.contains(&ip)
+      || self
+        .machine
+        .read(ip as i64)
+        .ok()
+        .and_then(|value| Instruction::decode(value).ok())
+        .is_some_and(|instruction| self.op_breakpoints.contains(&instruction.op))
+  }
+
+  fn value_at(&self, address: usize) -> i64 {
+    self.machine.memory().get(address).copied().unwrap_or(0)
+  }
+
+  pub fn step(&mut self) -> Result<Stop, Error> {
+    let watched: Vec<(usize, i64)> = self
+      .watchpoints
+      .iter()
+      .map(|&address| (address, self.value_at(address)))
+      .collect();
+    let ip = self.machine.ip();
+    let relative_base = self.machine.relative_base();
+    // memory may be about to change under the instruction
+    let text = self
+      .trace
+      .as_ref()
+      .map(|_| asm::instruction_at(self.machine.memory(), ip).unwrap_or_default());
+
+    let output = match self.machine.step().map_err(Error::Machine)? {
+      Some(Event::NeedsInput) => return Ok(Stop::NeedsInput),
+      Some(Event::Halted) => return Ok(Stop::Halted),
+      Some(Event::Output(value)) => {
+        self.outputs.push(value);
+        Some(value)
+      }
+      None => None,
+    };
+    self.steps += 1;
+
+    if let (Some(trace), Some(text)) = (self.trace.as_mut(), text) {
+      let line = match output {
+        Some(value) => format!("{} -> {}", text, value),
+        None => text,
+      };
+      writeln!(
+        trace,
+        "{:>8} {:04}: {:<32} rb={}",
+        self.steps, ip, line, relative_base
+      )
+      .map_err(Error::Trace)?;
+    }
+
+    for (address, old) in watched {
+      let new = self.value_at(address);
+      if new != old {
+        return Ok(Stop::Watchpoint { address, old, new });
+      }
+    }
+    Ok(Stop::Stepped)
+  }
+
+  // runs until something stops it. A breakpoint on the instruction it
+  // starts at doesn't, so it can be continued from.
+  pub fn cont(&mut self) -> Result<Stop, Error> {
+    self.run(None)
+  }
+
+  // as `cont`, but stops after `count` steps if nothing stops it sooner
+  pub fn step_by(&mut self, count: usize) -> Result<Stop, Error> {
+    if count == 0 {
+      return Ok(Stop::Stepped);
+    }
+    self.run(Some(count))
+  }
+
+  fn run(&mut self, limit: Option<usize>) -> Result<Stop, Error> {
+    let mut steps = 0;
+    loop {
+      let stop = self.step()?;
+      steps += 1;
+      if stop != Stop::Stepped || limit == Some(steps) {
+        return Ok(stop);
+      } else if self.breaks_here() {
+        return Ok(Stop::Breakpoint(self.machine.ip()));
+      }
+    }
+  }
+}
+
+const HELP: &str = "\
+step [n]              execute n instructions, default 1, stopping early
+                      like continue
+continue              run until a breakpoint, watchpoint, input or halt
+break <addr|op>       stop before an address, or before any instruction
+                      with a mnemonic such as out
+delete <addr|op>      remove a breakpoint
+watch <addr>          stop when the value at an address changes
+unwatch <addr>
+list                  show breakpoints and watchpoints
+regs                  show the instruction pointer and relative base
+mem <addr> [n]        show up to n values of memory, default 1
+set <addr> <value>    change memory
+input <value>...      queue input for the program
+dis [addr] [n]        disassemble n instructions, default 10 from ip
+trace <file>|off      log every instruction executed to a file
+reset                 start the program again
+quit";
+
+// a command line debugger for a program, reading commands from `input`
+// until it ends or says quit
+pub fn repl(program: &[i64], input: impl BufRead, mut out: impl Write) -> io::Result<()> {
+  let mut debugger = Debugger::new(Intcode::new(program));
+  writeln!(out, "{} values loaded, try help", program.len())?;
+  write!(out, "(intcode) ")?;
+  out.flush()?;
+
+  for line in input.lines() {
+    let line = line?;
+    let words: Vec<&str> = line.split_whitespace().collect();
+    if let ["q" | "quit"] = words[..] {
+      break;
+    }
+
+    match command(&mut debugger, program, &words) {
+      Ok(reply) => write!(out, "{}", reply)?,
+      Err(message) => writeln!(out, "error: {}", message)?,
+    }
+    for value in debugger.take_outputs() {
+      writeln!(out, "output: {}", value)?;
+    }
+    write!(out, "(intcode) ")?;
+    out.flush()?;
+  }
+
+  debugger.trace_to(None)
+}
+
+fn number<T: std::str::FromStr>(word: &str) -> Result<T, String> {
+  word
+    .parse()
+    .map_err(|_| format!("{} is not a number", word))
+}
+
+fn break_target(word: &str) -> Result<usize, String> {
+  word
+    .parse()
+    .map_err(|_| format!("{} is neither an address nor an op", word))
+}
+
+fn to_address(word: &str) -> Result<usize, String> {
+  word
+    .parse()
+    .map_err(|_| format!("{} is not an address", word))
+}
+
+fn describe(debugger: &Debugger, stop: Stop) -> String {
+  let ip = debugger.machine().ip();
+  let at = asm::instruction_at(debugger.machine().memory(), ip)
+    .map(|text| format!("{:04}: {}\n", ip, text))
+    .unwrap_or_default();
+  match stop {
+    Stop::Breakpoint(address) => format!("breakpoint at {}\n{}", address, at),
+    Stop::Watchpoint { address, old, new } => {
+      format!("[{}] changed from {} to {}\n{}", address, old, new, at)
+    }
+    Stop::NeedsInput => format!("waiting for input\n{}", at),
+    Stop::Halted => format!("halted after {} steps\n", debugger.steps()),
+    Stop::Stepped => at,
+  }
+}
+
+fn command(debugger: &mut Debugger, program: &[i64], words: &[&str]) -> Result<String, String> {
+  let machine_error = |e: Error| format!("{:?}", e);
+
+  match *words {
+    [] => Ok(String::new()),
+    ["help"] => Ok(format!("{}\n", HELP)),
+    ["s" | "step"] | ["s" | "step", _] => {
+      let count: usize = words.get(1).map_or(Ok(1), |w| number(w))?;
+      let stop = debugger.step_by(count).map_err(machine_error)?;
+      Ok(describe(debugger, stop))
+    }
+    ["c" | "continue"] => {
+      let stop = debugger.cont().map_err(machine_error)?;
+      Ok(describe(debugger, stop))
+    }
+    ["b" | "break", target] => match asm::op_named(target) {
+      Some(op) if debugger.break_on(op) => Ok(format!("breaking on {}\n", target)),
+      Some(_) => Err(format!("already breaking on {}", target)),
+      None if debugger.break_at(break_target(target)?) => Ok(format!("breaking at {}\n", target)),
+      None => Err(format!("already breaking at {}", target)),
+    },
+    ["d" | "delete", target] => {
+      let removed = match asm::op_named(target) {
+        Some(op) => debugger.clear_break_on(op),
+        None => debugger.clear_break_at(break_target(target)?),
+      };
+      match removed {
+        true => Ok(String::new()),
+        false => Err(format!("no breakpoint on {}", target)),
+      }
+    }
+    ["w" | "watch", address] => match debugger.watch(to_address(address)?) {
+      true => Ok(format!("watching [{}]\n", address)),
+      false => Err(format!("already watching [{}]", address)),
+    },
+    ["unwatch", address] => match debugger.unwatch(to_address(address)?) {
+      true => Ok(String::new()),
+      false => Err(format!("not watching [{}]", address)),
+    },
+    ["list"] => {
+      let mut reply = String::new();
+      for address in debugger.breakpoints() {
+        reply += &format!("break at {}\n", address);
+      }
+      for &op in debugger.op_breakpoints() {
+        reply += &format!("break on {}\n", asm::mnemonic(op));
+      }
+      for address in debugger.watchpoints() {
+        reply += &format!("watch [{}]\n", address);
+      }
+      Ok(reply)
+    }
+    ["r" | "regs"] => {
+      let machine = debugger.machine();
+      Ok(format!(
+        "ip={} rb={} steps={} queued inputs={}{}\n",
+        machine.ip(),
+        machine.relative_base(),
+        debugger.steps(),
+        machine.queued_inputs(),
+        if machine.is_halted() { " halted" } else { "" }
+      ))
+    }
+    ["x" | "mem", address] | ["x" | "mem", address, _] => {
+      let start = to_address(address)?;
+      let count: usize = words.get(2).map_or(Ok(1), |w| number(w))?;
+      let end = start
+        .checked_add(count)
+        .ok_or_else(|| format!("{} values from {} is too many", count, start))?;
+      // stop at the end of memory, but show at least the first cell
+      let end = end.min(
+        debugger
+          .machine()
+          .memory()
+          .len()
+          .max(start.saturating_add(1)),
+      );
+      let values: Vec<String> = (start..end)
+        .map(|a| debugger.value_at(a).to_string())
+        .collect();
+      Ok(format!("{:04}: {}\n", start, values.join(" ")))
+    }
+    ["set", address, value] => {
+      let (address, value) = (number(address)?, number(value)?);
+      debugger
+        .machine_mut()
+        .write(address, value)
+        .map_err(|e| format!("{:?}", e))?;
+      Ok(String::new())
+    }
+    ["i" | "input", ref values @ ..] if !values.is_empty() => {
+      for value in values {
+        let value = number(value)?;
+        debugger.machine_mut().push_input(value);
+      }
+      Ok(String::new())
+    }
+    ["dis"] | ["dis", _] | ["dis", _, _] => {
+      let mut address: usize = words
+        .get(1)
+        .map_or(Ok(debugger.machine().ip()), |w| number(w))?;
+      let count: usize = words.get(2).map_or(Ok(10), |w| number(w))?;
+      let memory = debugger.machine().memory();
+      let mut reply = String::new();
+      for _ in 0..count {
+        if address >= memory.len() {
+          break;
+        }
+        match asm::instruction_at(memory, address) {
+          Some(text) => {
+            reply += &format!("{:04}: {}\n", address, text);
+            let instruction = Instruction::decode(memory[address]).expect("just decoded");
+            address += 1 + instruction.op.arity();
+          }
+          None => {
+            reply += &format!("{:04}: data {}\n", address, memory[address]);
+            address += 1;
+          }
+        }
+      }
+      Ok(reply)
+    }
+    ["trace", "off"] => {
+      debugger.trace_to(None).map_err(|e| e.to_string())?;
+      Ok(String::new())
+    }
+    ["trace", path] => {
+      let file = File::create(path).map_err(|e| format!("cannot create {}: {}", path, e))?;
+      debugger
+        .trace_to(Some(Box::new(BufWriter::new(file))))
+        .map_err(|e| e.to_string())?;
+      Ok(format!("tracing to {}\n", path))
+    }
+    ["reset"] => {
+      debugger.reset(Intcode::new(program));
+      Ok(String::new())
+    }
+    _ => Err(format!("unknown command {:?}, try help", words.join(" "))),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{repl, Debugger, Stop};
+  use crate::intcode::{Intcode, Op};
+  use std::fs;
+
+  const PROGRAM: [i64; 12] = [1, 9, 10, 3, 2, 3, 11, 0, 99, 30, 40, 50];
+
+  #[test]
+  fn test_breakpoints() {
+    let mut debugger = Debugger::new(Intcode::new(&PROGRAM));
+    debugger.break_at(4);
+    debugger.break_on(Op::Halt);
+    assert_eq!(debugger.cont().unwrap(), Stop::Breakpoint(4));
+    assert_eq!(debugger.machine().read(3).unwrap(), 70);
+    assert_eq!(debugger.cont().unwrap(), Stop::Breakpoint(8));
+    assert_eq!(debugger.cont().unwrap(), Stop::Halted);
+    assert_eq!(debugger.steps(), 2);
+
+    let mut debugger = Debugger::new(Intcode::new(&PROGRAM));
+    debugger.watch(0);
+    assert_eq!(debugger.step().unwrap(), Stop::Stepped);
+    assert_eq!(
+      debugger.cont().unwrap(),
+      Stop::Watchpoint {
+        address: 0,
+        old: 1,
+        new: 3500
+      }
+    );
+    assert_eq!(debugger.machine().ip(), 8);
+
+    // stepping stops at breakpoints and watchpoints along the way
+    let mut debugger = Debugger::new(Intcode::new(&PROGRAM));
+    debugger.break_on(Op::Halt);
+    assert_eq!(debugger.step_by(100).unwrap(), Stop::Breakpoint(8));
+    let mut debugger = Debugger::new(Intcode::new(&PROGRAM));
+    debugger.watch(3);
+    assert_eq!(
+      debugger.step_by(100).unwrap(),
+      Stop::Watchpoint {
+        address: 3,
+        old: 3,
+        new: 70
+      }
+    );
+    assert_eq!(debugger.step_by(1).unwrap(), Stop::Stepped);
+    assert_eq!(debugger.step_by(0).unwrap(), Stop::Stepped);
+    assert_eq!(debugger.steps(), 2);
+
+    let mut debugger = Debugger::new(Intcode::new(&[3, 5, 4, 5, 99, 0]));
+    assert_eq!(debugger.cont().unwrap(), Stop::NeedsInput);
+    debugger.machine_mut().push_input(42);
+    assert_eq!(debugger.cont().unwrap(), Stop::Halted);
+    assert_eq!(debugger.take_outputs(), vec![42]);
+  }
+
+  #[test]
+  fn test_trace() {
+    let path = std::env::temp_dir().join(format!("intcode-trace-{}.log", std::process::id()));
+    let mut debugger = Debugger::new(Intcode::new(&[109, 3, 204, 1, 99]));
+    let file = fs::File::create(&path).unwrap();
+    debugger.trace_to(Some(Box::new(file))).unwrap();
+    debugger.cont().unwrap();
+    debugger.trace_to(None).unwrap();
+
+    let trace = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let lines: Vec<&str> = trace.lines().map(str::trim_end).collect();
+    assert_eq!(
+      lines,
+      vec![
+        "       1 0000: arb 3                            rb=0",
+        "       2 0002: out [rb+1] -> 99                 rb=3",
+      ]
+    );
+  }
+
+  #[test]
+  fn test_repl() {
+    let commands = "break 8\nwatch 3\ncontinue\nc\nset 0 7\nmem 0 4\nmem 10 100\nmem 40\nmem 18446744073709551615 2\nwatch -1\nbreak foo\nlist\ndis 4 2\nbogus\nreset\nstep 100\nregs\nq\nregs\n";
+    let mut out = Vec::new();
+    repl(&PROGRAM, commands.as_bytes(), &mut out).unwrap();
+    let out = String::from_utf8(out).unwrap();
+    let expected = "\
+12 values loaded, try help
+(intcode) breaking at 8
+(intcode) watching [3]
+(intcode) [3] changed from 3 to 70
+0004: mul [3], [11], [0]
+(intcode) breakpoint at 8
+0008: hlt
+(intcode) (intcode) 0000: 7 9 10 70
+(intcode) 0010: 40 50
+(intcode) 0040: 0
+(intcode) error: 2 values from 18446744073709551615 is too many
+(intcode) error: -1 is not an address
+(intcode) error: foo is neither an address nor an op
+(intcode) break at 8
+watch [3]
+(intcode) 0004: mul [3], [11], [0]
+0008: hlt
+(intcode) error: unknown command \"bogus\", try help
+(intcode) (intcode) [3] changed from 3 to 70
+0004: mul [3], [11], [0]
+(intcode) ip=4 rb=0 steps=1 queued inputs=0
+(intcode) ";
+    assert_eq!(out, expected);
+  }
+}
@@ -0,0 +1,527 @@
+use std::collections::VecDeque;
+
+// writes beyond this many cells are treated as out of bounds rather than
+// growing memory without bound
+const MAX_MEMORY: usize = 1 << 24;
+
+// the machine is left at the instruction that failed, see Intcode::ip
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+  InvalidOpcode(i64),
+  // the digit for parameter n (from 0) of an instruction isn't a mode
+  InvalidMode { instruction: i64, parameter: usize },
+  // a parameter that is written to is in immediate mode
+  ImmediateWrite { instruction: i64, parameter: usize },
+  OutOfBounds(i64),
+  // an add, multiply or relative base adjustment, or a relative mode address,
+  // that doesn't fit in an i64
+  Overflow,
+  // only from run, run_until_io reports NeedsInput instead
+  NoInput,
+}
+
+// why run_until_io stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+  // at an input instruction with nothing queued. Push some and run again.
+  NeedsInput,
+  Output(i64),
+  Halted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+  Add,
+  Multiply,
+  Input,
+  Output,
+  JumpIfTrue,
+  JumpIfFalse,
+  LessThan,
+  Equals,
+  AdjustBase,
+  Halt,
+}
+
+impl Op {
+  pub fn from_code(code: i64) -> Option<Op> {
+    match code {
+      1 => Some(Op::Add),
+      2 => Some(Op::Multiply),
+      3 => Some(Op::Input),
+      4 => Some(Op::Output),
+      5 => Some(Op::JumpIfTrue),
+      6 => Some(Op::JumpIfFalse),
+      7 => Some(Op::LessThan),
+      8 => Some(Op::Equals),
+      9 => Some(Op::AdjustBase),
+      99 => Some(Op::Halt),
+      _ => None,
+    }
+  }
+
+  // number of parameters following the instruction
+  pub fn arity(self) -> usize {
+    match self {
+      Op::Add | Op::Multiply | Op::LessThan | Op::Equals => 3,
+      Op::JumpIfTrue | Op::JumpIfFalse => 2,
+      Op::Input | Op::Output | Op::AdjustBase => 1,
+      Op::Halt => 0,
+    }
+  }
+}
+
+// the digit for each mode is its discriminant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+  Position = 0,
+  Immediate = 1,
+  Relative = 2,
+}
+
+impl Mode {
+  pub fn from_digit(digit: i64) -> Option<Mode> {
+    match digit {
+      0 => Some(Mode::Position),
+      1 => Some(Mode::Immediate),
+      2 => Some(Mode::Relative),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction {
+  pub op: Op,
+  // one per parameter, the rest are Position
+  pub modes: [Mode; 3],
+}
+
+impl Instruction {
+  // the two low digits are the opcode, and each digit above them the mode
+  // of a parameter, starting with the first
+  pub fn decode(value: i64) -> Result<Instruction, Error> {
+    if value < 0 {
+      return Err(Error::InvalidOpcode(value));
+    }
+
+    let op = Op::from_code(value % 100).ok_or(Error::InvalidOpcode(value))?;
+    let mut modes = [Mode::Position; 3];
+    let mut digits = value / 100;
+    let mut parameter = 0;
+    while digits != 0 {
+      match Mode::from_digit(digits % 10) {
+        Some(mode) if parameter < op.arity() => modes[parameter] = mode,
+        // zeros for parameters the op doesn't have are harmless
+        Some(Mode::Position) => {}
+        _ => {
+          return Err(Error::InvalidMode {
+            instruction: value,
+            parameter,
+          })
+        }
+      }
+      digits /= 10;
+      parameter += 1;
+    }
+
+    Ok(Instruction { op, modes })
+  }
+
+  pub fn encode(&self) -> i64 {
+    let code = match self.op {
+      Op::Add => 1,
+      Op::Multiply => 2,
+      Op::Input => 3,
+      Op::Output => 4,
+      Op::JumpIfTrue => 5,
+      Op::JumpIfFalse => 6,
+      Op::LessThan => 7,
+      Op::Equals => 8,
+      Op::AdjustBase => 9,
+      Op::Halt => 99,
+    };
+    let modes = self.modes[..self.op.arity()]
+      .iter()
+      .rev()
+      .fold(0, |digits, mode| digits * 10 + *mode as i64);
+    modes * 100 + code
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct Intcode {
+  memory: Vec<i64>,
+  ip: usize,
+  relative_base: i64,
+  inputs: VecDeque<i64>,
+  outputs: Vec<i64>,
+  halted: bool,
+}
+
+impl Intcode {
+  pub fn new(program: &[i64]) -> Intcode {
+    Intcode {
+      memory: program.to_vec(),
+      ip: 0,
+      relative_base: 0,
+      inputs: VecDeque::new(),
+      outputs: Vec::new(),
+      halted: false,
+    }
+  }
+
+  pub fn push_input(&mut self, value: i64) {
+    self.inputs.push_back(value);
+  }
+
+  // inputs pushed but not yet read
+  pub fn queued_inputs(&self) -> usize {
+    self.inputs.len()
+  }
+
+  // everything output since the last call
+  pub fn take_outputs(&mut self) -> Vec<i64> {
+    std::mem::take(&mut self.outputs)
+  }
+
+  pub fn memory(&self) -> &[i64] {
+    &self.memory
+  }
+
+  pub fn ip(&self) -> usize {
+    self.ip
+  }
+
+  pub fn relative_base(&self) -> i64 {
+    self.relative_base
+  }
+
+  pub fn is_halted(&self) -> bool {
+    self.halted
+  }
+
+  // cells past the end of memory read as 0
+  pub fn read(&self, address: i64) -> Result<i64, Error> {
+    let address = usize::try_from(address).map_err(|_| Error::OutOfBounds(address))?;
+    Ok(self.memory.get(address).copied().unwrap_or(0))
+  }
+
+  // memory grows to fit
+  pub fn write(&mut self, address: i64, value: i64) -> Result<(), Error> {
+    let idx = usize::try_from(address)
+      .ok()
+      .filter(|&idx| idx < MAX_MEMORY)
+      .ok_or(Error::OutOfBounds(address))?;
+    if idx >= self.memory.len() {
+      self.memory.resize(idx + 1, 0);
+    }
+    self.memory[idx] = value;
+    Ok(())
+  }
+
+  // runs until the program halts, collecting its output for take_outputs.
+  // Fails with NoInput if it asks for more input than has been pushed, and
+  // can be run again once there is some.
+  pub fn run(&mut self) -> Result<(), Error> {
+    loop {
+      match self.run_until_io()? {
+        Event::Output(value) => self.outputs.push(value),
+        Event::NeedsInput => return Err(Error::NoInput),
+        Event::Halted => return Ok(()),
+      }
+    }
+  }
+
+  // runs until the program outputs a value, halts, or waits for input
+  pub fn run_until_io(&mut self) -> Result<Event, Error> {
+    loop {
+      if let Some(event) = self.step()? {
+        return Ok(event);
+      }
+    }
+  }
+
+  // executes a single instruction, unless the program is waiting for input
+  // or has halted. An error leaves the machine as it was.
+  pub fn step(&mut self) -> Result<Option<Event>, Error> {
+    let instruction = Instruction::decode(self.read(self.ip as i64)?)?;
+    let mut next = self.ip + 1 + instruction.op.arity();
+    let mut event = None;
+
+    match instruction.op {
+      Op::Add => {
+        let value = self
+          .param(&instruction, 0)?
+          .checked_add(self.param(&instruction, 1)?)
+          .ok_or(Error::Overflow)?;
+        self.store(&instruction, 2, value)?;
+      }
+      Op::Multiply => {
+        let value = self
+          .param(&instruction, 0)?
+          .checked_mul(self.param(&instruction, 1)?)
+          .ok_or(Error::Overflow)?;
+        self.store(&instruction, 2, value)?;
+      }
+      Op::Input => {
+        let address = self.address(&instruction, 0)?;
+        let Some(&value) = self.inputs.front() else {
+          return Ok(Some(Event::NeedsInput));
+        };
+        self.write(address, value)?;
+        self.inputs.pop_front();
+      }
+      Op::Output => {
+        event = Some(Event::Output(self.param(&instruction, 0)?));
+      }
+      Op::JumpIfTrue | Op::JumpIfFalse => {
+        let condition = self.param(&instruction, 0)? != 0;
+        if condition == (instruction.op == Op::JumpIfTrue) {
+          let target = self.param(&instruction, 1)?;
+          next = usize::try_from(target).map_err(|_| Error::OutOfBounds(target))?;
+        }
+      }
+      Op::LessThan => {
+        let value = self.param(&instruction, 0)? < self.param(&instruction, 1)?;
+        self.store(&instruction, 2, value as i64)?;
+      }
+      Op::Equals => {
+        let value = self.param(&instruction, 0)? == self.param(&instruction, 1)?;
+        self.store(&instruction, 2, value as i64)?;
+      }
+      Op::AdjustBase => {
+        self.relative_base = self.relative(self.param(&instruction, 0)?)?;
+      }
+      Op::Halt => {
+        self.halted = true;
+        return Ok(Some(Event::Halted));
+      }
+    }
+
+    self.ip = next;
+    Ok(event)
+  }
+
+  fn relative(&self, offset: i64) -> Result<i64, Error> {
+    self
+      .relative_base
+      .checked_add(offset)
+      .ok_or(Error::Overflow)
+  }
+
+  fn raw(&self, n: usize) -> Result<i64, Error> {
+    self.read((self.ip + 1 + n) as i64)
+  }
+
+  fn param(&self, instruction: &Instruction, n: usize) -> Result<i64, Error> {
+    let raw = self.raw(n)?;
+    match instruction.modes[n] {
+      Mode::Position => self.read(raw),
+      Mode::Immediate => Ok(raw),
+      Mode::Relative => self.read(self.relative(raw)?),
+    }
+  }
+
+  // where parameter n is written to
+  fn address(&self, instruction: &Instruction, n: usize) -> Result<i64, Error> {
+    let raw = self.raw(n)?;
+    match instruction.modes[n] {
+      Mode::Position => Ok(raw),
+      Mode::Relative => self.relative(raw),
+      Mode::Immediate => Err(Error::ImmediateWrite {
+        instruction: self.read(self.ip as i64)?,
+        parameter: n,
+      }),
+    }
+  }
+
+  fn store(&mut self, instruction: &Instruction, n: usize, value: i64) -> Result<(), Error> {
+    let address = self.address(instruction, n)?;
+    self.write(address, value)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Error, Event, Instruction, Intcode, Mode, Op};
+
+  fn run(program: &[i64], inputs: &[i64]) -> Vec<i64> {
+    let mut machine = Intcode::new(program);
+    for &input in inputs {
+      machine.push_input(input);
+    }
+    machine.run().unwrap();
+    machine.take_outputs()
+  }
+
+  #[test]
+  fn test_decode() {
+    assert_eq!(
+      Instruction::decode(1002).unwrap(),
+      Instruction {
+        op: Op::Multiply,
+        modes: [Mode::Position, Mode::Immediate, Mode::Position],
+      }
+    );
+    assert_eq!(Instruction::decode(204).unwrap().modes[0], Mode::Relative);
+    assert_eq!(Instruction::decode(42), Err(Error::InvalidOpcode(42)));
+    assert_eq!(
+      Instruction::decode(301),
+      Err(Error::InvalidMode {
+        instruction: 301,
+        parameter: 0
+      })
+    );
+    // a mode for a parameter that Halt doesn't have
+    assert_eq!(
+      Instruction::decode(199),
+      Err(Error::InvalidMode {
+        instruction: 199,
+        parameter: 0
+      })
+    );
+    assert_eq!(Instruction::decode(99).unwrap().op, Op::Halt);
+    for value in [1, 99, 1002, 21101, 204, 1105, 22207] {
+      assert_eq!(Instruction::decode(value).unwrap().encode(), value);
+    }
+  }
+
+  #[test]
+  fn test_comparisons_and_jumps() {
+    // outputs 999 below 8, 1000 at 8 and 1001 above
+    let program = [
+      3, 21, 1008, 21, 8, 20, 1005, 20, 22, 107, 8, 21, 20, 1006, 20, 31, 1106, 0, 36, 98, 0, 0,
+      1002, 21, 125, 20, 4, 20, 1105, 1, 46, 104, 999, 1105, 1, 46, 1101, 1000, 1, 20, 4, 20, 1105,
+      1, 46, 98, 99,
+    ];
+    assert_eq!(run(&program, &[7]), vec![999]);
+    assert_eq!(run(&program, &[8]), vec![1000]);
+    assert_eq!(run(&program, &[9]), vec![1001]);
+
+    // is the input non-zero, with position and immediate jumps
+    let program = [3, 12, 6, 12, 15, 1, 13, 14, 13, 4, 13, 99, -1, 0, 1, 9];
+    assert_eq!(run(&program, &[0]), vec![0]);
+    assert_eq!(run(&program, &[5]), vec![1]);
+    let program = [3, 3, 1105, -1, 9, 1101, 0, 0, 12, 4, 12, 99, 1];
+    assert_eq!(run(&program, &[0]), vec![0]);
+    assert_eq!(run(&program, &[5]), vec![1]);
+  }
+
+  #[test]
+  fn test_relative_base_and_memory() {
+    // a quine, writing well past the end of its own memory
+    let quine = [
+      109, 1, 204, -1, 1001, 100, 1, 100, 1008, 100, 16, 101, 1006, 101, 0, 99,
+    ];
+    assert_eq!(run(&quine, &[]), quine.to_vec());
+
+    assert_eq!(
+      run(&[1102, 34915192, 34915192, 7, 4, 7, 99, 0], &[]),
+      vec![1219070632396864]
+    );
+    assert_eq!(
+      run(&[104, 1125899906842624, 99], &[]),
+      vec![1125899906842624]
+    );
+
+    let mut machine = Intcode::new(&[109, 5, 21101, 2, 3, 1000, 99]);
+    machine.run().unwrap();
+    assert_eq!(machine.relative_base(), 5);
+    assert_eq!(machine.memory().len(), 1006);
+    assert_eq!(machine.read(1005).unwrap(), 5);
+  }
+
+  #[test]
+  fn test_waits_for_input() {
+    let mut machine = Intcode::new(&[3, 20, 3, 21, 1, 20, 21, 22, 4, 22, 99]);
+    machine.push_input(2);
+    assert_eq!(machine.run(), Err(Error::NoInput));
+    assert!(!machine.is_halted());
+    machine.push_input(3);
+    machine.run().unwrap();
+    assert!(machine.is_halted());
+    assert_eq!(machine.take_outputs(), vec![5]);
+  }
+
+  #[test]
+  fn test_run_until_io() {
+    // doubles each input until given 0
+    let mut machine = Intcode::new(&[
+      3, 100, 1006, 100, 14, 1002, 100, 2, 100, 4, 100, 1105, 1, 0, 99,
+    ]);
+    assert_eq!(machine.run_until_io(), Ok(Event::NeedsInput));
+    assert_eq!(machine.run_until_io(), Ok(Event::NeedsInput));
+    for n in [3, 10, -4] {
+      machine.push_input(n);
+      assert_eq!(machine.run_until_io(), Ok(Event::Output(n * 2)));
+      assert_eq!(machine.run_until_io(), Ok(Event::NeedsInput));
+    }
+    machine.push_input(0);
+    assert_eq!(machine.run_until_io(), Ok(Event::Halted));
+    assert_eq!(machine.run_until_io(), Ok(Event::Halted));
+
+    let mut machine = Intcode::new(&[104, 7, 99]);
+    assert_eq!(machine.step(), Ok(Some(Event::Output(7))));
+    assert_eq!(machine.ip(), 2);
+    assert_eq!(machine.step(), Ok(Some(Event::Halted)));
+    assert!(machine.is_halted());
+  }
+
+  #[test]
+  fn test_errors() {
+    let fault = |program: &[i64]| {
+      let mut machine = Intcode::new(program);
+      machine.push_input(1);
+      let error = machine.run().unwrap_err();
+      (error, machine.ip())
+    };
+
+    assert_eq!(fault(&[1101, 1, 1, 5, 77]), (Error::InvalidOpcode(77), 4));
+    assert_eq!(fault(&[1, 0, 0, -1, 99]), (Error::OutOfBounds(-1), 0));
+    assert_eq!(fault(&[1, -3, 0, 0, 99]), (Error::OutOfBounds(-3), 0));
+    assert_eq!(fault(&[109, -5, 204, 1, 99]), (Error::OutOfBounds(-4), 2));
+    assert_eq!(fault(&[1106, 0, -1, 99]), (Error::OutOfBounds(-1), 0));
+    assert_eq!(
+      fault(&[1, 0, 0, 1 << 40, 99]),
+      (Error::OutOfBounds(1 << 40), 0)
+    );
+    assert_eq!(
+      fault(&[11101, 1, 1, 0, 99]),
+      (
+        Error::ImmediateWrite {
+          instruction: 11101,
+          parameter: 2
+        },
+        0
+      )
+    );
+    assert_eq!(
+      fault(&[1, 0, 0, 0, 50204, 0, 99]),
+      (
+        Error::InvalidMode {
+          instruction: 50204,
+          parameter: 2
+        },
+        4
+      )
+    );
+
+    let max = i64::MAX;
+    assert_eq!(fault(&[1101, max, 1, 0, 99]), (Error::Overflow, 0));
+    assert_eq!(fault(&[1102, max, 2, 0, 99]), (Error::Overflow, 0));
+    assert_eq!(fault(&[109, max, 109, 1, 99]), (Error::Overflow, 2));
+    assert_eq!(fault(&[109, max, 1201, 1, 1, 0, 99]), (Error::Overflow, 2));
+    assert_eq!(fault(&[109, max, 21101, 1, 1, 1, 99]), (Error::Overflow, 2));
+    let mut machine = Intcode::new(&[109, 2, 1101, max, 1, 0, 99]);
+    assert!(machine.run().is_err());
+    assert_eq!(machine.memory(), &[109, 2, 1101, max, 1, 0, 99]);
+    assert_eq!(machine.relative_base(), 2);
+
+    // a failed input leaves memory as it was
+    let mut machine = Intcode::new(&[103, 0, 99]);
+    machine.push_input(5);
+    assert!(machine.run().is_err());
+    assert_eq!(machine.memory(), &[103, 0, 99]);
+  }
+}
@@ -0,0 +1,8 @@
+pub mod asm;
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod debugger;
+pub mod intcode;
+pub mod network;
@@ -0,0 +1,344 @@
+use crate::intcode::{self, Event, Intcode};
+
+// the address of the NAT in a packet switched network
+pub const NAT: i64 = 255;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+  Machine { idx: usize, error: intcode::Error },
+  // every machine is waiting for input that nothing is going to send
+  Deadlock,
+  // a packet for an address with no machine
+  Unroutable { from: usize, to: i64 },
+  // every machine in a chain halted without the last one outputting
+  NoOutput,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+  // instructions executed
+  pub steps: usize,
+  // values delivered to the machine, not counting the -1s of a packet
+  // switched network
+  pub received: usize,
+  pub sent: usize,
+  // times the machine stopped to wait for input it didn't have
+  pub waits: usize,
+}
+
+// why Network::run stopped running a machine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+  Waiting,
+  Halted,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Packet {
+  pub to: i64,
+  pub x: i64,
+  pub y: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NatEvent {
+  Received(Packet),
+  // the network was idle, so the NAT sent the last packet it received on to
+  // address 0
+  Woke(Packet),
+}
+
+// copies of a program that pass values to each other, run a machine at a
+// time until each needs input it doesn't have or halts
+pub struct Network {
+  machines: Vec<Intcode>,
+  stats: Vec<Stats>,
+}
+
+impl Network {
+  pub fn new(program: &[i64], size: usize) -> Network {
+    Network {
+      machines: vec![Intcode::new(program); size],
+      stats: vec![Stats::default(); size],
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.machines.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.machines.is_empty()
+  }
+
+  pub fn machine(&self, idx: usize) -> &Intcode {
+    &self.machines[idx]
+  }
+
+  pub fn stats(&self) -> &[Stats] {
+    &self.stats
+  }
+
+  pub fn send(&mut self, to: usize, value: i64) {
+    self.machines[to].push_input(value);
+    self.stats[to].received += 1;
+  }
+
+  // runs one machine until it halts or needs input, returning its outputs
+  pub fn run(&mut self, idx: usize) -> Result<(Vec<i64>, State), Error> {
+    let machine = &mut self.machines[idx];
+    let stats = &mut self.stats[idx];
+    let mut outputs = Vec::new();
+
+    loop {
+      match machine
+        .step()
+        .map_err(|error| Error::Machine { idx, error })?
+      {
+        None => stats.steps += 1,
+        Some(Event::Output(value)) => {
+          stats.steps += 1;
+          stats.sent += 1;
+          outputs.push(value);
+        }
+        Some(Event::NeedsInput) => {
+          stats.waits += 1;
+          return Ok((outputs, State::Waiting));
+        }
+        Some(Event::Halted) => return Ok((outputs, State::Halted)),
+      }
+    }
+  }
+
+  // each machine's output is input to the next, and with feedback the last
+  // machine's output goes back to the first. Runs until every machine has
+  // halted and returns the last value the last machine output.
+  pub fn chain(&mut self, feedback: bool) -> Result<i64, Error> {
+    let size = self.len();
+    let mut last = None;
+
+    loop {
+      let mut sent = false;
+      let mut halted = 0;
+      for idx in 0..size {
+        let (outputs, state) = self.run(idx)?;
+        if idx + 1 == size {
+          last = outputs.last().copied().or(last);
+        }
+
+        let next = match idx + 1 {
+          next if next < size => Some(next),
+          _ if feedback => Some(0),
+          _ => None,
+        };
+        if let Some(next) = next {
+          sent |= !outputs.is_empty();
+          for value in outputs {
+            self.send(next, value);
+          }
+        }
+        if state == State::Halted {
+          halted += 1;
+        }
+      }
+
+      // machines that are waiting had read everything sent to them by the
+      // time they stopped, so a round without sends can't make progress
+      if halted == size {
+        return last.ok_or(Error::NoOutput);
+      } else if !sent {
+        return Err(Error::Deadlock);
+      }
+    }
+  }
+
+  // gives each machine its address, then -1 whenever it asks for input and
+  // no packet has arrived. Machines send packets as three outputs: the
+  // address, x and y. The NAT keeps the last packet sent to it, and sends
+  // it on to address 0 whenever a round passes with no packets sent or
+  // waiting. `watch` sees what the NAT does, and stops the network by
+  // returning Some. Fails with Deadlock if the network goes idle with
+  // nothing for the NAT to send, or nothing at address 0 to wake.
+  pub fn switch<T>(&mut self, mut watch: impl FnMut(NatEvent) -> Option<T>) -> Result<T, Error> {
+    let size = self.len();
+    for idx in 0..size {
+      self.send(idx, idx as i64);
+    }
+    let mut partial: Vec<Vec<i64>> = vec![Vec::new(); size];
+    let mut nat = None;
+
+    loop {
+      if self.machines.iter().all(Intcode::is_halted) {
+        return Err(Error::Deadlock);
+      }
+
+      let mut idle = true;
+      for (idx, partial) in partial.iter_mut().enumerate() {
+        if self.machines[idx].is_halted() {
+          continue;
+        } else if self.machines[idx].queued_inputs() == 0 {
+          self.machines[idx].push_input(-1);
+        } else {
+          idle = false;
+        }
+
+        let (outputs, _) = self.run(idx)?;
+        partial.extend(outputs);
+        while partial.len() >= 3 {
+          let (to, x, y) = (partial[0], partial[1], partial[2]);
+          partial.drain(..3);
+          idle = false;
+
+          if to == NAT {
+            let packet = Packet { to, x, y };
+            nat = Some(packet);
+            if let Some(result) = watch(NatEvent::Received(packet)) {
+              return Ok(result);
+            }
+          } else {
+            let to = usize::try_from(to)
+              .ok()
+              .filter(|&to| to < size)
+              .ok_or(Error::Unroutable { from: idx, to })?;
+            self.send(to, x);
+            self.send(to, y);
+          }
+        }
+      }
+
+      if idle {
+        if self.machines[0].is_halted() {
+          return Err(Error::Deadlock);
+        }
+        let packet = Packet {
+          to: 0,
+          ..nat.ok_or(Error::Deadlock)?
+        };
+        self.send(0, packet.x);
+        self.send(0, packet.y);
+        if let Some(result) = watch(NatEvent::Woke(packet)) {
+          return Ok(result);
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Error, NatEvent, Network, Stats};
+  use crate::intcode;
+
+  fn amplify(program: &[i64], phases: &[i64], feedback: bool) -> Result<i64, Error> {
+    let mut network = Network::new(program, phases.len());
+    for (idx, &phase) in phases.iter().enumerate() {
+      network.send(idx, phase);
+    }
+    network.send(0, 0);
+    network.chain(feedback)
+  }
+
+  #[test]
+  fn test_chain() {
+    let program = [
+      3, 15, 3, 16, 1002, 16, 10, 16, 1, 16, 15, 15, 4, 15, 99, 0, 0,
+    ];
+    assert_eq!(amplify(&program, &[4, 3, 2, 1, 0], false), Ok(43210));
+
+    let program = [
+      3, 26, 1001, 26, -4, 26, 3, 27, 1002, 27, 2, 27, 1, 27, 26, 27, 4, 27, 1001, 28, -1, 28,
+      1005, 28, 6, 99, 0, 0, 5,
+    ];
+    assert_eq!(amplify(&program, &[9, 8, 7, 6, 5], true), Ok(139629729));
+
+    let mut network = Network::new(&program, 5);
+    for (idx, phase) in [9, 8, 7, 6, 5].into_iter().enumerate() {
+      network.send(idx, phase);
+    }
+    network.send(0, 0);
+    network.chain(true).unwrap();
+    // five times round the loop, and the first machine was also given 0
+    let stats = network.stats();
+    assert_eq!((stats[0].received, stats[0].sent), (7, 5));
+    for stats in &stats[1..] {
+      assert_eq!((stats.received, stats.sent), (6, 5));
+    }
+  }
+
+  #[test]
+  fn test_chain_errors() {
+    // each machine waits for the one before it, and nothing starts the first
+    let mut network = Network::new(&[3, 0, 3, 0, 4, 0, 99], 2);
+    network.send(0, 1);
+    network.send(1, 2);
+    assert_eq!(network.chain(true), Err(Error::Deadlock));
+    assert_eq!(amplify(&[3, 0, 99], &[1, 2], false), Err(Error::NoOutput));
+    assert_eq!(
+      amplify(&[3, 0, 4, 0, 42], &[1, 2], false),
+      Err(Error::Machine {
+        idx: 0,
+        error: intcode::Error::InvalidOpcode(42)
+      })
+    );
+  }
+
+  #[test]
+  fn test_switch() {
+    // sends (255, address, address * 10) then reads forever
+    let program = [
+      3, 100, 104, 255, 4, 100, 1002, 100, 10, 101, 4, 101, 3, 102, 1105, 1, 12,
+    ];
+
+    let mut network = Network::new(&program, 50);
+    let first = network.switch(|event| match event {
+      NatEvent::Received(packet) => Some(packet.y),
+      NatEvent::Woke(_) => None,
+    });
+    assert_eq!(first, Ok(0));
+
+    // the NAT wakes machine 0 with the last packet until the same y is sent
+    // twice running
+    let mut network = Network::new(&program, 50);
+    let mut last = None;
+    let repeated = network.switch(|event| match event {
+      NatEvent::Received(_) => None,
+      NatEvent::Woke(packet) => last.replace(packet.y).filter(|&y| y == packet.y),
+    });
+    assert_eq!(repeated, Ok(490));
+    assert_eq!(
+      network.stats()[0],
+      Stats {
+        steps: 13,
+        received: 5,
+        sent: 3,
+        waits: 4,
+      }
+    );
+    assert_eq!(network.stats()[1].received, 1);
+
+    let mut network = Network::new(&[104, 50, 104, 1, 104, 2, 99], 50);
+    assert_eq!(
+      network.switch(|_| Some(())),
+      Err(Error::Unroutable { from: 0, to: 50 })
+    );
+    let mut network = Network::new(&[3, 0, 99], 2);
+    assert_eq!(network.switch(|_| Some(())), Err(Error::Deadlock));
+
+    // machine 0 sends (255, 1, 2) and halts, the rest read forever, so the
+    // NAT has nobody to wake
+    let program = [
+      3, 100, 1005, 100, 15, 104, 255, 104, 1, 104, 2, 99, 0, 0, 0, 3, 101, 1105, 1, 15,
+    ];
+    let mut network = Network::new(&program, 3);
+    let mut received = 0;
+    let result = network.switch(|event| match event {
+      NatEvent::Received(_) => {
+        received += 1;
+        None::<()>
+      }
+      NatEvent::Woke(_) => panic!("woke a halted machine"),
+    });
+    assert_eq!(result, Err(Error::Deadlock));
+    assert_eq!(received, 1);
+  }
+}
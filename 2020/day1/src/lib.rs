@@ -0,0 +1,99 @@
+use aoc_common::{input, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    SolutionNotFoundError,
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<i64>, Error> {
+        let mut elements = input::parse_int_per_line::<i64>(source).map_err(Error::Input)?;
+        elements.sort();
+        Ok(elements)
+    }
+
+    fn part_one(elements: &Vec<i64>, _: &()) -> Result<i64, Error> {
+        find_pair(elements).map(|(a, b)| a * b)
+    }
+
+    fn part_two(elements: &Vec<i64>, _: &()) -> Result<i64, Error> {
+        find_triple(elements).map(|(a, b, c)| a * b * c)
+    }
+}
+
+// expects the elements to be sorted
+pub fn find_pair(elements: &[i64]) -> Result<(i64, i64), Error> {
+    for (idx, &left) in elements.iter().enumerate() {
+        for &right in elements.iter().skip(idx + 1) {
+            let sum = left + right;
+            if sum == 2020 {
+                return Ok((left, right));
+            } else if sum > 2020 {
+                break;
+            }
+        }
+    }
+
+    Err(Error::SolutionNotFoundError)
+}
+
+// expects the elements to be sorted
+pub fn find_triple(elements: &[i64]) -> Result<(i64, i64, i64), Error> {
+    for (left_idx, &left) in elements.iter().enumerate() {
+        for (middle_idx, &middle) in elements[left_idx + 1..].iter().enumerate() {
+            if middle + left > 2020 {
+                break;
+            }
+
+            for &right in elements[left_idx + middle_idx + 1..].iter() {
+                let sum = left + middle + right;
+                if sum == 2020 {
+                    return Ok((left, middle, right));
+                } else if sum > 2020 {
+                    break;
+                }
+            }
+        }
+    }
+
+    Err(Error::SolutionNotFoundError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_pair, find_triple, Day1};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let elements = Day1::parse(Source::file("1_test.txt")).unwrap();
+        let ret = find_pair(&elements);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (299, 1721));
+        assert_eq!(Day1::part_one(&elements, &()).unwrap(), 514579);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let elements = Day1::parse(Source::file("1_test.txt")).unwrap();
+        let ret = find_triple(&elements);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (366, 675, 979));
+        assert_eq!(Day1::part_two(&elements, &()).unwrap(), 241861950);
+    }
+
+    #[test]
+    fn test_inline_input() {
+        let elements = Day1::parse(Source::text("1721\n979\n366\n299\n675\n1456\n")).unwrap();
+        assert_eq!(Day1::part_one(&elements, &()).unwrap(), 514579);
+    }
+}
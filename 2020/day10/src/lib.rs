@@ -0,0 +1,131 @@
+use aoc_common::number::Count;
+use aoc_common::{input, Solution, Source};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    SolutionNotFound,
+    Count(aoc_common::Error),
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<i64>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = Count;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<i64>, Error> {
+        let mut numbers = input::parse_int_per_line::<i64>(source).map_err(Error::Input)?;
+        numbers.push(0);
+        numbers.sort();
+        Ok(numbers)
+    }
+
+    fn part_one(numbers: &Vec<i64>, _: &()) -> Result<i64, Error> {
+        joltage_differences(numbers).map(|(diffs_1, diffs_3)| diffs_1 * diffs_3)
+    }
+
+    fn part_two(numbers: &Vec<i64>, _: &()) -> Result<Count, Error> {
+        count_arrangements(numbers)
+    }
+}
+
+// ways to chain adapters from the outlet to the device, working back from
+// the device
+pub fn count_arrangements(adapters: &[i64]) -> Result<Count, Error> {
+    let mut arrangements: HashMap<i64, Count> = HashMap::new();
+    arrangements.insert(
+        adapters.last().ok_or(Error::SolutionNotFound)? + 3,
+        Count::from(1),
+    );
+
+    for &i in adapters.iter().rev() {
+        let ways = [i + 1, i + 2, i + 3]
+            .iter()
+            .filter_map(|j| arrangements.get(j))
+            .try_fold(Count::default(), |total, ways| total.checked_add(ways))
+            .map_err(Error::Count)?;
+        arrangements.insert(i, ways);
+    }
+
+    arrangements.remove(&0).ok_or(Error::SolutionNotFound)
+}
+
+// expects the adapters to be sorted, starting with the outlet
+pub fn joltage_differences(adapters: &[i64]) -> Result<(i64, i64), Error> {
+    let mut numbers = adapters.to_vec();
+    numbers.push(numbers.last().ok_or(Error::SolutionNotFound)? + 3);
+
+    let mut diff_counts: HashMap<i64, i64> = HashMap::new();
+
+    for i in 1..numbers.len() {
+        *diff_counts.entry(numbers[i] - numbers[i - 1]).or_insert(0) += 1;
+    }
+
+    Ok((*diff_counts.get(&1).unwrap(), *diff_counts.get(&3).unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{count_arrangements, joltage_differences, Day10};
+    use aoc_common::number::Count;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one_a() {
+        let test_input = "1_test.txt";
+        let input = Day10::parse(Source::file(test_input)).unwrap();
+        let ret = joltage_differences(&input);
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), (7, 5));
+        assert_eq!(Day10::part_one(&input, &()).unwrap(), 35);
+    }
+
+    #[test]
+    fn test_part_one_b() {
+        let test_input = "2_test.txt";
+        let input = Day10::parse(Source::file(test_input)).unwrap();
+        let ret = joltage_differences(&input);
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), (22, 10));
+        assert_eq!(Day10::part_one(&input, &()).unwrap(), 220);
+    }
+
+    #[test]
+    fn test_part_two_a() {
+        let test_input = "1_test.txt";
+        let input = Day10::parse(Source::file(test_input)).unwrap();
+        let ret = Day10::part_two(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), Count::from(8));
+    }
+
+    #[test]
+    fn test_part_two_b() {
+        let test_input = "2_test.txt";
+        let input = Day10::parse(Source::file(test_input)).unwrap();
+        let ret = Day10::part_two(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), Count::from(19208));
+    }
+
+    #[test]
+    fn test_long_chain() {
+        // with every joltage from 0 to n, the ways to reach each adapter
+        // follow the tribonacci numbers
+        let adapters: Vec<i64> = (0..=100).collect();
+        let res = count_arrangements(&adapters);
+
+        #[cfg(not(feature = "bigint"))]
+        assert!(matches!(
+            res,
+            Err(super::Error::Count(aoc_common::Error::Arithmetic(_)))
+        ));
+        #[cfg(feature = "bigint")]
+        assert_eq!(res.unwrap().to_string(), "180396380815100901214157639");
+    }
+}
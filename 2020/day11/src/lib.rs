@@ -0,0 +1,142 @@
+use aoc_common::automaton::{Automaton, Dense, Graph, Neighbourhood, Offsets, Point, Rule};
+use aoc_common::grid::{Pos, ADJACENT};
+#[cfg(feature = "render")]
+use aoc_common::{
+    automaton::Cells,
+    render::{Animated, Frame, Recorder},
+};
+use aoc_common::{Grid, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Square {
+    Empty,
+    Occupied,
+    Floor,
+}
+
+fn point((x, y): Pos) -> Point<2> {
+    [x as i64, y as i64]
+}
+
+fn seats(area: &Grid<Square>) -> impl Iterator<Item = Pos> + '_ {
+    area.iter()
+        .filter(|(_, &sq)| sq != Square::Floor)
+        .map(|(p, _)| p)
+}
+
+// the first seat seen in each of the eight directions, looking across the floor
+fn visible_seats(area: &Grid<Square>) -> Graph<2> {
+    Graph::new(
+        seats(area)
+            .map(|pos| {
+                let visible = ADJACENT
+                    .iter()
+                    .filter_map(|&dir| area.ray(pos, dir).find(|&p| area[p] != Square::Floor))
+                    .map(point)
+                    .collect();
+                (point(pos), visible)
+            })
+            .collect(),
+    )
+}
+
+// empty seats with no occupied neighbours fill up, occupied seats with at
+// least `crowded` occupied neighbours empty
+fn seating<N: Neighbourhood<2>>(
+    area: &Grid<Square>,
+    neighbours: N,
+    crowded: usize,
+) -> Automaton<2, Dense<2>, N> {
+    let occupied = area
+        .iter()
+        .filter(|(_, &sq)| sq == Square::Occupied)
+        .map(|(p, _)| point(p));
+    let rule = Rule::new(&[0], &(0..crowded).collect::<Vec<_>>());
+
+    Automaton::within(occupied, rule, neighbours, seats(area).map(point))
+}
+
+fn occupied_when_stable(mut layout: Automaton<2, Dense<2>, impl Neighbourhood<2>>) -> usize {
+    layout.settle();
+    layout.len()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<Square>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Grid<Square>, Error> {
+        Grid::parse(source, |c| match c {
+            'L' => Some(Square::Empty),
+            '.' => Some(Square::Floor),
+            '#' => Some(Square::Occupied),
+            _ => None,
+        })
+        .map_err(Error::Input)
+    }
+
+    fn part_one(area: &Grid<Square>, _: &()) -> Result<usize, Error> {
+        Ok(occupied_when_stable(seating(area, Offsets::moore(), 4)))
+    }
+
+    fn part_two(area: &Grid<Square>, _: &()) -> Result<usize, Error> {
+        Ok(occupied_when_stable(seating(area, visible_seats(area), 5)))
+    }
+}
+
+// the floor plan with occupied seats lit up, one frame per round
+#[cfg(feature = "render")]
+impl Animated for Day11 {
+    fn record(area: &Grid<Square>, part: u8) -> Recorder {
+        let mut recorder = Recorder::new(&[[32, 32, 32], [96, 96, 96], [240, 200, 64]]);
+        let floor_plan = Frame::grid(area, |&sq| if sq == Square::Floor { 0 } else { 1 });
+        let each = |cells: &Dense<2>| {
+            let mut frame = floor_plan.clone();
+            for [x, y] in cells.live() {
+                frame.set(x as usize, y as usize, 2);
+            }
+            recorder.push(frame);
+        };
+
+        if part == 1 {
+            seating(area, Offsets::moore(), 4).settle_with(each);
+        } else {
+            seating(area, visible_seats(area), 5).settle_with(each);
+        }
+        recorder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day11::parse(Source::file(test_input)).unwrap();
+        let ret = Day11::part_one(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 37);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day11::parse(Source::file(test_input)).unwrap();
+        let ret = Day11::part_two(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 26);
+    }
+}
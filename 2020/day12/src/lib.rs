@@ -0,0 +1,187 @@
+use aoc_common::{Diagnostic, Solution, Source};
+use regex::Regex;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    Regex(regex::Error),
+    UnknownFacing(i64),
+    ParseLine(Diagnostic),
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    North(i64),
+    South(i64),
+    East(i64),
+    West(i64),
+    Left(i64),
+    Right(i64),
+    Forward(i64),
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Instruction>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<Instruction>, Error> {
+        let path = source.name().to_string();
+        let rgx = Regex::new(r"(N|S|E|W|L|R|F)([0-9]+)").map_err(Error::Regex)?;
+        source
+            .lines()
+            .enumerate()
+            .map(|(idx, maybe_line)| -> Result<Instruction, Error> {
+                let line = maybe_line.map_err(Error::IO)?;
+                let diagnostic = |token: &str, message: &str| {
+                    Error::ParseLine(Diagnostic::new(&path, idx + 1, &line, token, message))
+                };
+
+                let captures = rgx
+                    .captures(&line)
+                    .ok_or_else(|| diagnostic(&line, "expected an action and a value"))?;
+                let dir = &captures[1];
+                let val = captures[2]
+                    .parse::<i64>()
+                    .map_err(|e| diagnostic(&captures[2], &e.to_string()))?;
+
+                match dir {
+                    "N" => Ok(Instruction::North(val)),
+                    "S" => Ok(Instruction::South(val)),
+                    "E" => Ok(Instruction::East(val)),
+                    "W" => Ok(Instruction::West(val)),
+                    "L" => Ok(Instruction::Left(val)),
+                    "R" => Ok(Instruction::Right(val)),
+                    "F" => Ok(Instruction::Forward(val)),
+                    _ => Err(diagnostic(dir, "unknown action")),
+                }
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn part_one(instructions: &Vec<Instruction>, _: &()) -> Result<i64, Error> {
+        navigate(instructions).map(|(ew, ns)| ew + ns)
+    }
+
+    fn part_two(instructions: &Vec<Instruction>, _: &()) -> Result<i64, Error> {
+        navigate_waypoint(instructions).map(|(ew, ns)| ew + ns)
+    }
+}
+
+pub fn navigate_waypoint(instructions: &[Instruction]) -> Result<(i64, i64), Error> {
+    let mut ship_north_south = 0;
+    let mut ship_east_west = 0;
+    let mut wp_north_south = 1;
+    let mut wp_east_west = 10;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::North(v) => wp_north_south += v,
+            Instruction::South(v) => wp_north_south -= v,
+            Instruction::East(v) => wp_east_west += v,
+            Instruction::West(v) => wp_east_west -= v,
+            Instruction::Right(v) => {
+                let ew = wp_east_west;
+                let ns = wp_north_south;
+                match v {
+                    90 => {
+                        wp_north_south = -ew;
+                        wp_east_west = ns;
+                    }
+                    270 => {
+                        wp_north_south = ew;
+                        wp_east_west = -ns;
+                    }
+                    180 => {
+                        wp_north_south = -ns;
+                        wp_east_west = -ew;
+                    }
+                    _ => return Err(Error::UnknownFacing(*v)),
+                }
+            }
+            Instruction::Left(v) => {
+                let ew = wp_east_west;
+                let ns = wp_north_south;
+                match v {
+                    90 => {
+                        wp_north_south = ew;
+                        wp_east_west = -ns;
+                    }
+                    270 => {
+                        wp_north_south = -ew;
+                        wp_east_west = ns;
+                    }
+                    180 => {
+                        wp_north_south = -ns;
+                        wp_east_west = -ew;
+                    }
+                    _ => return Err(Error::UnknownFacing(*v)),
+                }
+            }
+            Instruction::Forward(v) => {
+                ship_east_west += wp_east_west * v;
+                ship_north_south += wp_north_south * v;
+            }
+        }
+    }
+
+    Ok((ship_east_west.abs(), ship_north_south.abs()))
+}
+
+pub fn navigate(instructions: &[Instruction]) -> Result<(i64, i64), Error> {
+    let mut facing = 90; // east
+    let mut north_south = 0;
+    let mut east_west = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::North(v) => north_south += v,
+            Instruction::South(v) => north_south -= v,
+            Instruction::East(v) => east_west += v,
+            Instruction::West(v) => east_west -= v,
+            Instruction::Left(v) => facing = (facing - v + 360) % 360,
+            Instruction::Right(v) => facing = (facing + v) % 360,
+            Instruction::Forward(v) => match facing {
+                90 => east_west += v,
+                270 => east_west -= v,
+                0 => north_south += v,
+                180 => north_south -= v,
+                _ => return Err(Error::UnknownFacing(facing)),
+            },
+        }
+    }
+
+    Ok((east_west.abs(), north_south.abs()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{navigate, navigate_waypoint, Day12};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day12::parse(Source::file(test_input)).unwrap();
+        let ret = navigate(&input);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (17, 8));
+        assert_eq!(Day12::part_one(&input, &()).unwrap(), 25);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day12::parse(Source::file(test_input)).unwrap();
+        let ret = navigate_waypoint(&input);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (214, 72));
+        assert_eq!(Day12::part_two(&input, &()).unwrap(), 286);
+    }
+}
@@ -0,0 +1,166 @@
+use aoc_common::number::{self, BigInt};
+use aoc_common::{Diagnostic, Solution, Source};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    ParseLine(Diagnostic),
+    Parse,
+    Schedule(aoc_common::Error),
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (i64, Vec<(i64, i64)>);
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = BigInt;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<(i64, Vec<(i64, i64)>), Error> {
+        let path = source.name().to_string();
+        let lines: Vec<String> = source
+            .lines()
+            .map(|maybe_line| -> Result<String, Error> { maybe_line.map_err(Error::IO) })
+            .into_iter()
+            .collect::<Result<Vec<String>, Error>>()?;
+
+        if lines.len() != 2 {
+            return Err(Error::Parse);
+        }
+
+        let diagnostic = |line: usize, token: &str, message: String| {
+            Error::ParseLine(Diagnostic::new(
+                &path,
+                line + 1,
+                &lines[line],
+                token,
+                message,
+            ))
+        };
+
+        let earliest_start = &lines[0]
+            .parse::<i64>()
+            .map_err(|e| diagnostic(0, &lines[0], e.to_string()))?;
+        let buses_ids = (&lines[1])
+            .split(",")
+            .enumerate()
+            .filter(|(_, id_str)| *id_str != "x")
+            .map(|(idx, id_str)| -> Result<(i64, i64), Error> {
+                let id = id_str
+                    .parse::<i64>()
+                    .map_err(|e| diagnostic(1, id_str, e.to_string()))?;
+                Ok((id, idx as i64))
+            })
+            .into_iter()
+            .collect::<Result<Vec<(i64, i64)>, Error>>()?;
+
+        Ok((*earliest_start, buses_ids))
+    }
+
+    fn part_one(input: &(i64, Vec<(i64, i64)>), _: &()) -> Result<i64, Error> {
+        earliest_bus(input).map(|(id, minutes)| id * minutes)
+    }
+
+    fn part_two(input: &(i64, Vec<(i64, i64)>), _: &()) -> Result<BigInt, Error> {
+        let (_, bus_ids) = input;
+
+        earliest_timestamp(bus_ids)
+    }
+}
+
+pub fn earliest_bus(input: &(i64, Vec<(i64, i64)>)) -> Result<(i64, i64), Error> {
+    let (earliest_start, bus_ids_and_indexes) = input;
+    let bus_ids: Vec<i64> = bus_ids_and_indexes.iter().map(|(id, _)| *id).collect();
+
+    let mut earliest_bus_time = -1;
+    let mut earliest_bus_id = -1;
+
+    for id in bus_ids {
+        let start_offset = earliest_start % id;
+        let next_bus = id - start_offset;
+
+        if earliest_bus_time == -1 {
+            earliest_bus_id = id;
+            earliest_bus_time = next_bus;
+        } else if next_bus < earliest_bus_time {
+            earliest_bus_id = id;
+            earliest_bus_time = next_bus;
+        }
+    }
+
+    Ok((earliest_bus_id, earliest_bus_time))
+}
+
+// the first time each bus leaves its offset in minutes after the first,
+// i.e. t + offset ≡ 0 (mod id) for every bus
+fn earliest_timestamp(buses: &[(i64, i64)]) -> Result<BigInt, Error> {
+    number::crt(buses.iter().map(|&(id, offset)| (-offset, id)))
+        .map(|(timestamp, _)| timestamp)
+        .map_err(Error::Schedule)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{earliest_bus, earliest_timestamp, Day13, Error};
+    use aoc_common::number::BigInt;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day13::parse(Source::file(test_input)).unwrap();
+        let ret = earliest_bus(&input);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (59, 5));
+        assert_eq!(Day13::part_one(&input, &()).unwrap(), 295);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day13::parse(Source::file(test_input)).unwrap();
+        let ret = Day13::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), BigInt::from(1068781));
+    }
+
+    #[test]
+    fn test_earliest_timestamp() {
+        let timestamp = |buses: &[(i64, i64)]| earliest_timestamp(buses).unwrap();
+        assert_eq!(timestamp(&[(17, 0), (13, 2), (19, 3)]), BigInt::from(3417));
+        assert_eq!(
+            timestamp(&[(67, 0), (7, 1), (59, 2), (61, 3)]),
+            BigInt::from(754018)
+        );
+        assert_eq!(
+            timestamp(&[(67, 0), (7, 2), (59, 3), (61, 4)]),
+            BigInt::from(779210)
+        );
+        assert_eq!(
+            timestamp(&[(67, 0), (7, 1), (59, 3), (61, 4)]),
+            BigInt::from(1261476)
+        );
+        assert_eq!(
+            timestamp(&[(1789, 0), (37, 1), (47, 2), (1889, 3)]),
+            BigInt::from(1202161486)
+        );
+    }
+
+    #[test]
+    fn test_shared_factors() {
+        // 4 and 6 share a factor of 2, so the two buses must agree on whether t is even
+        assert_eq!(
+            earliest_timestamp(&[(4, 0), (6, 2)]).unwrap(),
+            BigInt::from(4)
+        );
+        assert!(matches!(
+            earliest_timestamp(&[(4, 0), (6, 1)]),
+            Err(Error::Schedule(aoc_common::Error::Inconsistent { .. }))
+        ));
+    }
+}
+
+// -41
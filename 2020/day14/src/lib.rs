@@ -0,0 +1,175 @@
+use aoc_common::{Diagnostic, Solution, Source};
+use regex::Regex;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    ParseLine(Diagnostic),
+    RegexError(regex::Error),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Instruction {
+    Mask(u64, u64, u64),
+    MemSet(u64, u64),
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Instruction>;
+    type Config = ();
+    type PartOne = u64;
+    type PartTwo = u64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<Instruction>, Error> {
+        let path = source.name().to_string();
+        // mask = 100X000X01XX11X10X01X11100101XX11101
+        let mask_rgx = Regex::new(r"mask = ([10X]+)").map_err(Error::RegexError)?;
+        // mem[7] = 101
+        let memset_rgx = Regex::new(r"mem\[([0-9]+)\] = ([0-9]+)").map_err(Error::RegexError)?;
+        source
+            .lines()
+            .enumerate()
+            .map(|(idx, maybe_line)| -> Result<Instruction, Error> {
+                let line = maybe_line.map_err(Error::IO)?;
+                let diagnostic = |token: &str, message: &str| {
+                    Error::ParseLine(Diagnostic::new(&path, idx + 1, &line, token, message))
+                };
+
+                if let Some(captures) = memset_rgx.captures(&line) {
+                    let parse_int = |x: usize| -> Result<u64, Error> {
+                        let token = captures[x].to_string();
+                        token
+                            .parse::<u64>()
+                            .map_err(|e| diagnostic(&token, &e.to_string()))
+                    };
+
+                    Ok(Instruction::MemSet(parse_int(1)?, parse_int(2)?))
+                } else if let Some(captures) = mask_rgx.captures(&line) {
+                    let capture: &str = captures.get(1).map_or("", |m| m.as_str());
+
+                    if capture.len() != 36 {
+                        return Err(diagnostic(capture, "mask must be 36 bits long"));
+                    }
+
+                    let mut mask_ones: u64 = 0;
+                    let mut mask_zeroes: u64 = 0;
+                    let mut mask_xs: u64 = 0;
+
+                    for (idx, c) in capture.chars().rev().enumerate() {
+                        match c {
+                            '1' => mask_ones |= 1 << idx,
+                            '0' => mask_zeroes |= 1 << idx,
+                            'X' => mask_xs |= 1 << idx,
+                            _ => unreachable!(),
+                        }
+                    }
+
+                    Ok(Instruction::Mask(mask_ones, mask_zeroes, mask_xs))
+                } else {
+                    Err(diagnostic(
+                        &line,
+                        "expected \"mask = ...\" or \"mem[...] = ...\"",
+                    ))
+                }
+            })
+            .into_iter()
+            .collect::<Result<Vec<Instruction>, Error>>()
+    }
+
+    fn part_one(instructions: &Vec<Instruction>, _: &()) -> Result<u64, Error> {
+        let mut mask_ones = 0;
+        let mut mask_zeroes = 0;
+        let mut memory: HashMap<u64, u64> = HashMap::new();
+
+        for &instruction in instructions {
+            match instruction {
+                Instruction::Mask(ones, zeroes, _) => {
+                    mask_ones = ones;
+                    mask_zeroes = zeroes;
+                }
+                Instruction::MemSet(address, value) => {
+                    let mut val = value | mask_ones;
+                    val &= u64::MAX ^ mask_zeroes;
+                    memory.insert(address, val);
+                }
+            }
+        }
+
+        Ok(memory.iter().fold(0, |s, (_, v)| s + v))
+    }
+
+    fn part_two(instructions: &Vec<Instruction>, _: &()) -> Result<u64, Error> {
+        let mut mask_ones = 0;
+        let mut mask_xs = 0;
+        let mut memory: HashMap<u64, u64> = HashMap::new();
+
+        for &instruction in instructions {
+            match instruction {
+                Instruction::Mask(ones, _, xs) => {
+                    mask_ones = ones;
+                    mask_xs = xs;
+                }
+                Instruction::MemSet(address, value) => {
+                    // If the bitmask bit is 0, the corresponding memory address bit is unchanged.
+                    // If the bitmask bit is 1, the corresponding memory address bit is overwritten with 1.
+                    let updated_address = address | mask_ones;
+                    // If the bitmask bit is X, the corresponding memory address bit is floating.
+
+                    let floating_address_count: u64 = 1 << mask_xs.count_ones();
+                    let mut floating_address_idx_masks: Vec<u64> = Vec::new();
+                    let mut xs: u64 = mask_xs;
+                    while xs != 0 {
+                        let bit = xs & 0u64.wrapping_sub(xs);
+                        floating_address_idx_masks.push(bit as u64);
+                        xs ^= bit;
+                    }
+
+                    for i in 0..floating_address_count {
+                        let mut addr = updated_address;
+                        for j in 0..floating_address_idx_masks.len() {
+                            let idx_mask: u64 = floating_address_idx_masks[j];
+
+                            if i & (1 << j) != 0 {
+                                addr |= idx_mask;
+                            } else {
+                                addr &= u64::MAX ^ idx_mask;
+                            }
+                        }
+                        memory.insert(addr, value);
+                    }
+                }
+            }
+        }
+
+        Ok(memory.iter().fold(0, |s, (_, v)| s + v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day14;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day14::parse(Source::file(test_input)).unwrap();
+        let ret = Day14::part_one(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 165);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "2_test.txt";
+        let input = Day14::parse(Source::file(test_input)).unwrap();
+        let ret = Day14::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 208);
+    }
+}
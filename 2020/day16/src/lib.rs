@@ -0,0 +1,273 @@
+use aoc_common::{Diagnostic, Sections, Solution, Source};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    Parse(Diagnostic),
+    Regex(regex::Error),
+    NotImplemented,
+}
+
+#[derive(Debug)]
+pub struct Info {
+    my_ticket: Vec<i64>,
+    nearby_tickets: Vec<Vec<i64>>,
+    fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    name: String,
+    low_range_min: i64,
+    low_range_max: i64,
+    high_range_min: i64,
+    high_range_max: i64,
+}
+
+pub struct Config {
+    // part two multiplies the values of my ticket's fields with this prefix
+    pub field_prefix: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            field_prefix: "departure".to_string(),
+        }
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Info;
+    type Config = Config;
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Info, Error> {
+        let parts = Sections::read(source)
+            .map_err(Error::Input)?
+            .expect(3)
+            .map_err(Error::Input)?;
+
+        // FIELDS
+        let mut fields: Vec<Field> = Vec::new();
+        // arrival station: 43-301 or 309-961
+        let field_rgx =
+            Regex::new(r"([A-z\s]+): (\d+)-(\d+) or (\d+)-(\d+)").map_err(Error::Regex)?;
+
+        for (idx, line) in parts[0].iter().enumerate() {
+            let diagnostic =
+                |token: &str, message: &str| Error::Parse(parts.diagnostic(0, idx, token, message));
+
+            let captures = field_rgx
+                .captures(&line)
+                .ok_or_else(|| diagnostic(line, "expected \"<name>: <a>-<b> or <c>-<d>\""))?;
+
+            let parse_num = |x: usize| -> Result<i64, Error> {
+                captures[x]
+                    .parse::<i64>()
+                    .map_err(|e| diagnostic(&captures[x], &e.to_string()))
+            };
+
+            fields.push(Field {
+                name: captures[1].to_string(),
+                low_range_min: parse_num(2)?,
+                low_range_max: parse_num(3)?,
+                high_range_min: parse_num(4)?,
+                high_range_max: parse_num(5)?,
+            });
+        }
+
+        // MY TICKET
+        let my_ticket_str = parts[1]
+            .get(1)
+            .ok_or_else(|| Error::Parse(parts.diagnostic(1, 0, &parts[1][0], "missing ticket")))?;
+
+        let my_ticket = parse_comma_separated_nums(&parts, 1, 1, my_ticket_str)?;
+
+        // NEARBY TICKETS
+        let nearby_tickets: Vec<Vec<i64>> = parts[2]
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(idx, line)| parse_comma_separated_nums(&parts, 2, idx, line))
+            .into_iter()
+            .collect::<Result<Vec<Vec<i64>>, Error>>()?;
+
+        Ok(Info {
+            fields: fields,
+            my_ticket: my_ticket,
+            nearby_tickets: nearby_tickets,
+        })
+    }
+
+    fn part_one(input: &Info, _: &Config) -> Result<i64, Error> {
+        let info = &input;
+
+        let mut invalid_values: Vec<i64> = Vec::new();
+
+        for ticket in info.nearby_tickets.iter() {
+            for &val in ticket.iter() {
+                let mut is_valid = false;
+                for field in info.fields.iter() {
+                    if (val >= field.low_range_min && val <= field.low_range_max)
+                        || (val >= field.high_range_min && val <= field.high_range_max)
+                    {
+                        is_valid = true;
+                        break;
+                    }
+                }
+                if !is_valid {
+                    invalid_values.push(val);
+                }
+            }
+        }
+
+        Ok(invalid_values.iter().fold(0, |s, v| s + v))
+    }
+
+    fn part_two(input: &Info, config: &Config) -> Result<i64, Error> {
+        let info = &input;
+
+        let valid_tickets: Vec<&Vec<i64>> = info
+            .nearby_tickets
+            .iter()
+            .filter(|t| ticket_is_valid(t, &info.fields))
+            .collect();
+
+        let ticket_index_count = info.nearby_tickets[0].len();
+
+        // maps field names to possible indexes for that field
+        let mut possible_field_positions: HashMap<String, HashSet<usize>> = HashMap::new();
+        let mut unique_field_positions: HashMap<String, usize> = HashMap::new();
+
+        // STEP 1: FIND POSSIBLE INDEXES FOR EACH FIELD
+        for field in &info.fields {
+            for idx in 0..ticket_index_count {
+                let mut valid_for_all_tickets = true;
+                for ticket in &valid_tickets {
+                    let val = ticket[idx];
+                    let valid_for_this_ticket = (val >= field.low_range_min
+                        && val <= field.low_range_max)
+                        || (val >= field.high_range_min && val <= field.high_range_max);
+
+                    if !valid_for_this_ticket {
+                        valid_for_all_tickets = false;
+                        break;
+                    }
+                }
+                if valid_for_all_tickets {
+                    possible_field_positions
+                        .entry(field.name.to_string())
+                        .or_insert(HashSet::new())
+                        .insert(idx);
+                }
+            }
+        }
+
+        // STEP 2: FOR EACH INDEX UNIQUELY ASSOCIATED WITH A FIELD,
+        // REMOVE THAT INDEX FROM OTHER FIELDS
+        loop {
+            if unique_field_positions.len() == info.fields.len() {
+                break;
+            }
+            // Remove all unique indexes from possible field possitions list
+            for (_, idx) in &unique_field_positions {
+                for (_, possible_indexes) in &mut possible_field_positions {
+                    possible_indexes.remove(idx);
+                }
+            }
+
+            // If there is only one possible index for any field
+            // then add to list of found positions
+            for (field_name, indexes) in &possible_field_positions {
+                if unique_field_positions.contains_key(field_name) {
+                    continue;
+                }
+                if indexes.len() == 1 {
+                    let idx = indexes.iter().next().unwrap();
+                    unique_field_positions.insert(field_name.to_string(), *idx);
+                }
+            }
+        }
+
+        let mut my_ticket_values: HashMap<String, i64> = HashMap::new();
+
+        for (field_name, idx) in &unique_field_positions {
+            let value = info.my_ticket[*idx as usize];
+            my_ticket_values.insert(field_name.to_string(), value);
+        }
+
+        let departure_mult = my_ticket_values
+            .iter()
+            .filter(|(k, _)| k.starts_with(&config.field_prefix))
+            .fold(1, |s, (_, v)| s * v);
+
+        Ok(departure_mult)
+    }
+}
+
+fn ticket_is_valid(ticket: &Vec<i64>, fields: &Vec<Field>) -> bool {
+    for &val in ticket.iter() {
+        let mut value_is_valid = false;
+        for field in fields.iter() {
+            if (val >= field.low_range_min && val <= field.low_range_max)
+                || (val >= field.high_range_min && val <= field.high_range_max)
+            {
+                value_is_valid = true;
+                break;
+            }
+        }
+        if !value_is_valid {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn parse_comma_separated_nums(
+    parts: &Sections,
+    section: usize,
+    line: usize,
+    s: &str,
+) -> Result<Vec<i64>, Error> {
+    s.split(",")
+        .map(|num| {
+            num.parse::<i64>()
+                .map_err(|e| Error::Parse(parts.diagnostic(section, line, num, e.to_string())))
+        })
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Day16};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day16::parse(Source::file(test_input)).unwrap();
+        let ret = Day16::part_one(&input, &Config::default());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 71);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "2_test.txt";
+        let input = Day16::parse(Source::file(test_input)).unwrap();
+        let config = Config {
+            field_prefix: String::new(),
+        };
+        let ret = Day16::part_two(&input, &config);
+        assert_eq!(ret.unwrap(), 12 * 13 * 11);
+    }
+}
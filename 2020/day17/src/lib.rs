@@ -0,0 +1,126 @@
+use aoc_common::automaton::{Automaton, Dense, Offsets, Rule};
+#[cfg(feature = "render")]
+use aoc_common::{
+    automaton::Cells,
+    render::{Animated, Recorder, Slices},
+};
+use aoc_common::{Solution, Source};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    NotImplemented,
+}
+
+// Conway Cubes: a slice of active cubes in D dimensions
+fn pocket<const D: usize>(slice: &HashSet<(i64, i64)>) -> Automaton<D, Dense<D>, Offsets<D>> {
+    let cubes = slice.iter().map(|&(x, y)| {
+        let mut p = [0; D];
+        p[0] = x;
+        p[1] = y;
+        p
+    });
+
+    Automaton::new(cubes, Rule::new(&[3], &[2, 3]), Offsets::moore())
+        .expect("cubes only become active next to active ones")
+}
+
+// the number of active cubes after the six boot cycles
+fn active_after_boot<const D: usize>(slice: &HashSet<(i64, i64)>) -> usize {
+    let mut pocket = pocket::<D>(slice);
+    pocket.run(BOOT_CYCLES);
+    pocket.len()
+}
+
+#[cfg(feature = "render")]
+fn record_boot<const D: usize>(slice: &HashSet<(i64, i64)>) -> Recorder {
+    let mut slices = Slices::<D>::new();
+    pocket::<D>(slice).run_with(BOOT_CYCLES, |cubes| slices.push(cubes.live()));
+
+    let mut recorder = Recorder::new(&[[0, 0, 0], [24, 32, 64], [120, 220, 255]]).delay(50);
+    for frame in slices.frames(1, 2) {
+        recorder.push(frame);
+    }
+    recorder
+}
+
+const BOOT_CYCLES: usize = 6;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = HashSet<(i64, i64)>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<HashSet<(i64, i64)>, Error> {
+        let mut active_vec: Vec<(i64, i64)> = Vec::new();
+        for (y, maybe_line) in source.lines().enumerate() {
+            let line = maybe_line.map_err(Error::IOError)?;
+            for (x, c) in line.chars().enumerate() {
+                if c == '#' {
+                    active_vec.push((x as i64, y as i64))
+                }
+            }
+        }
+        let mut active_set: HashSet<(i64, i64)> = HashSet::new();
+        for coord in active_vec {
+            active_set.insert(coord);
+        }
+        Ok(active_set)
+    }
+
+    fn part_one(slice: &HashSet<(i64, i64)>, _: &()) -> Result<usize, Error> {
+        Ok(active_after_boot::<3>(slice))
+    }
+
+    fn part_two(slice: &HashSet<(i64, i64)>, _: &()) -> Result<usize, Error> {
+        Ok(active_after_boot::<4>(slice))
+    }
+}
+
+// every z-slice side by side, with a row of them for each w in part two
+#[cfg(feature = "render")]
+impl Animated for Day17 {
+    fn record(slice: &HashSet<(i64, i64)>, part: u8) -> Recorder {
+        if part == 1 {
+            record_boot::<3>(slice)
+        } else {
+            record_boot::<4>(slice)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{active_after_boot, Day17};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day17::parse(Source::file(test_input)).unwrap();
+        let ret = Day17::part_one(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 112);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day17::parse(Source::file(test_input)).unwrap();
+        let ret = Day17::part_two(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 848);
+    }
+
+    #[test]
+    fn test_five_dimensions() {
+        let input = Day17::parse(Source::file("1_test.txt")).unwrap();
+        assert_eq!(active_after_boot::<5>(&input), 5760);
+    }
+}
@@ -0,0 +1,169 @@
+use aoc_common::expr::{Associativity, Operators};
+use aoc_common::{Diagnostic, Solution, Source};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    Expression(aoc_common::Error),
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<String>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<String>, Error> {
+        let path = source.name().to_string();
+        source
+            .lines()
+            .enumerate()
+            .map(|(idx, maybe_line)| -> Result<String, Error> {
+                let line = maybe_line.map_err(Error::IO)?;
+                // both tables share their symbols, so either catches bad lines
+                left_to_right().parse(&line).map_err(|e| match e {
+                    aoc_common::Error::Parse(diagnostic) => {
+                        Error::Expression(aoc_common::Error::Parse(Diagnostic {
+                            path: path.clone(),
+                            line: idx + 1,
+                            ..diagnostic
+                        }))
+                    }
+                    e => Error::Expression(e),
+                })?;
+                Ok(line)
+            })
+            .collect()
+    }
+
+    fn part_one(problems: &Vec<String>, _: &()) -> Result<i64, Error> {
+        sum_answers(problems, &left_to_right())
+    }
+
+    fn part_two(problems: &Vec<String>, _: &()) -> Result<i64, Error> {
+        sum_answers(problems, &addition_first())
+    }
+}
+
+// + and * bind equally, so evaluate left to right
+fn left_to_right() -> Operators {
+    Operators::new()
+        .binary('+', 1, Associativity::Left, i64::checked_add)
+        .binary('*', 1, Associativity::Left, i64::checked_mul)
+}
+
+fn addition_first() -> Operators {
+    Operators::new()
+        .binary('+', 2, Associativity::Left, i64::checked_add)
+        .binary('*', 1, Associativity::Left, i64::checked_mul)
+}
+
+fn solve_problem(problem: &str, operators: &Operators) -> Result<i64, Error> {
+    operators
+        .parse(problem)
+        .and_then(|expr| expr.eval())
+        .map_err(Error::Expression)
+}
+
+fn sum_answers(problems: &[String], operators: &Operators) -> Result<i64, Error> {
+    let mut total: i64 = 0;
+    for problem in problems {
+        let answer = solve_problem(problem, operators)?;
+        total = total.checked_add(answer).ok_or_else(|| {
+            Error::Expression(aoc_common::Error::Arithmetic(format!(
+                "{} + {}",
+                total, answer
+            )))
+        })?;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{addition_first, left_to_right, solve_problem, sum_answers, Day18, Error};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_rejects_rpn() {
+        match Day18::parse(Source::text("1 + 2\n3 4 5 * +\n")) {
+            Err(Error::Expression(aoc_common::Error::Parse(d))) => {
+                assert_eq!((d.line, d.column), (2, 3))
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_solve_problem() {
+        let ops = left_to_right();
+        assert_eq!(solve_problem("2 * 3 + (4 * 5)", &ops).unwrap(), 26);
+        assert_eq!(
+            solve_problem("5 + (8 * 3 + 9 + 3 * 4 * 3)", &ops).unwrap(),
+            437
+        );
+        assert_eq!(
+            solve_problem("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", &ops).unwrap(),
+            12240
+        );
+        assert_eq!(
+            solve_problem("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", &ops).unwrap(),
+            13632
+        );
+
+        let ops = addition_first();
+        assert_eq!(solve_problem("2 * 3 + (4 * 5)", &ops).unwrap(), 46);
+        assert_eq!(
+            solve_problem("5 + (8 * 3 + 9 + 3 * 4 * 3)", &ops).unwrap(),
+            1445
+        );
+        assert_eq!(
+            solve_problem("5 * 9 * (7 * 3 * 3 + 9 * 3 + (8 + 6 * 4))", &ops).unwrap(),
+            669060
+        );
+        assert_eq!(
+            solve_problem("((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2", &ops).unwrap(),
+            23340
+        );
+    }
+
+    #[test]
+    fn test_sum_overflows() {
+        let problems = vec!["4611686018427387904".to_string(); 2];
+        match sum_answers(&problems, &left_to_right()) {
+            Err(Error::Expression(aoc_common::Error::Arithmetic(sum))) => {
+                assert_eq!(sum, "4611686018427387904 + 4611686018427387904")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+        assert_eq!(
+            sum_answers(&problems[..1], &left_to_right()).unwrap(),
+            1 << 62
+        );
+    }
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(
+            left_to_right().parse("12 + (2 * 30)").unwrap().to_string(),
+            "(12 + (2 * 30))"
+        );
+        assert_eq!(
+            left_to_right().parse("1 + 2 * 3").unwrap().to_string(),
+            "((1 + 2) * 3)"
+        );
+        assert_eq!(
+            addition_first().parse("1 * 2 + 3").unwrap().to_string(),
+            "(1 * (2 + 3))"
+        );
+        assert_eq!(
+            solve_problem("12 * 10 + 3", &addition_first()).unwrap(),
+            156
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use aoc_common::{input, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+#[derive(Debug)]
+pub struct Entry {
+    min: usize,
+    max: usize,
+    letter: char,
+    password: Vec<char>,
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Entry>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<Entry>, Error> {
+        input::parse_regex_records(source, r"([0-9]+)-([0-9]+) ([A-z]): ([A-z]+)", |captures| {
+            Ok(Entry {
+                min: input::capture_int(captures, 1)?,
+                max: input::capture_int(captures, 2)?,
+                letter: input::capture_str(captures, 3)?
+                    .chars()
+                    .collect::<Vec<char>>()[0],
+                password: input::capture_str(captures, 4)?.chars().collect(),
+            })
+        })
+        .map_err(Error::Input)
+    }
+
+    fn part_one(entries: &Vec<Entry>, _: &()) -> Result<usize, Error> {
+        Ok(entries
+            .iter()
+            .filter(|e| {
+                let count = e.password.iter().filter(|&&c| c == e.letter).count();
+                count >= e.min && count <= e.max
+            })
+            .count())
+    }
+
+    fn part_two(entries: &Vec<Entry>, _: &()) -> Result<usize, Error> {
+        Ok(entries
+            .iter()
+            .filter(|e| (e.password[e.min - 1] == e.letter) ^ (e.password[e.max - 1] == e.letter))
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day2;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day2::parse(Source::file(test_input)).unwrap();
+        let ret = Day2::part_one(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day2::parse(Source::file(test_input)).unwrap();
+        let ret = Day2::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 1);
+    }
+}
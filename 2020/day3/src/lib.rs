@@ -0,0 +1,64 @@
+use aoc_common::{Grid, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+pub struct Day3;
+
+// trees hit going down the repeating course `dx` right for every `dy` down
+fn trees_hit(course: &Grid<bool>, dx: usize, dy: usize) -> usize {
+    (0..course.height())
+        .step_by(dy)
+        .enumerate()
+        .filter(|&(step, y)| *course.wrapped((step * dx, y)))
+        .count()
+}
+
+impl Solution for Day3 {
+    type Input = Grid<bool>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Grid<bool>, Error> {
+        Grid::parse(source, |c| Some(c == '#')).map_err(Error::Input)
+    }
+
+    fn part_one(course: &Grid<bool>, _: &()) -> Result<usize, Error> {
+        Ok(trees_hit(course, 3, 1))
+    }
+
+    fn part_two(course: &Grid<bool>, _: &()) -> Result<usize, Error> {
+        Ok([(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)]
+            .iter()
+            .map(|&(dx, dy)| trees_hit(course, dx, dy))
+            .product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day3;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day3::parse(Source::file(test_input)).unwrap();
+        let ret = Day3::part_one(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 7);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day3::parse(Source::file(test_input)).unwrap();
+        let ret = Day3::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 336);
+    }
+}
@@ -0,0 +1,153 @@
+use aoc_common::{Diagnostic, Sections, Solution, Source};
+use regex::Regex;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    RegexError(regex::Error),
+    ParseField(Diagnostic),
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<HashMap<String, String>>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<HashMap<String, String>>, Error> {
+        let sections = Sections::read(source).map_err(Error::Input)?;
+
+        sections
+            .iter()
+            .enumerate()
+            .map(
+                |(section, passport)| -> Result<HashMap<String, String>, Error> {
+                    let mut field_map = HashMap::new();
+
+                    for res in passport
+                        .iter()
+                        .enumerate()
+                        .flat_map(|(line, text)| text.split_whitespace().map(move |f| (line, f)))
+                        .map(|(line, field)| -> Result<(String, String), Error> {
+                            field
+                                .split_once(":")
+                                .ok_or_else(|| {
+                                    Error::ParseField(sections.diagnostic(
+                                        section,
+                                        line,
+                                        field,
+                                        "expected \"key:value\"",
+                                    ))
+                                })
+                                .map(|(a, b)| (a.to_owned(), b.to_owned()))
+                        })
+                        .into_iter()
+                    {
+                        let (k, v) = res?;
+                        field_map.insert(k, v);
+                    }
+
+                    Ok(field_map)
+                },
+            )
+            .into_iter()
+            .collect()
+    }
+
+    fn part_one(passports: &Vec<HashMap<String, String>>, _: &()) -> Result<usize, Error> {
+        let required_fields: std::collections::HashSet<&str> =
+            HashSet::from_iter(["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]);
+
+        Ok(passports
+            .iter()
+            .filter(|fields| {
+                required_fields
+                    .iter()
+                    .all(|&k| fields.contains_key(k as &str))
+            })
+            .count())
+    }
+
+    fn part_two(passports: &Vec<HashMap<String, String>>, _: &()) -> Result<usize, Error> {
+        let required_fields: std::collections::HashSet<&str> =
+            HashSet::from_iter(["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"]);
+
+        let hcl_rgx = Regex::new(r"^#[0-9a-f]{6}$").map_err(Error::RegexError)?;
+        let ecl_rgx = Regex::new(r"^amb|blu|brn|gry|grn|hzl|oth$").map_err(Error::RegexError)?;
+        let pid_rgx = Regex::new(r"^[0-9]{9}$").map_err(Error::RegexError)?;
+        let hgt_in_regex = Regex::new(r"^[0-9]+in$").map_err(Error::RegexError)?;
+        let hgt_cm_regex = Regex::new(r"^[0-9]+cm$").map_err(Error::RegexError)?;
+        Ok(passports
+            .iter()
+            .filter(|fields| {
+                required_fields
+                    .iter()
+                    .all(|&k| fields.contains_key(k as &str))
+            })
+            .filter(|fields| {
+                let byr = fields.get("byr").unwrap().parse::<i64>().unwrap();
+                byr >= 1920 && byr <= 2002
+            })
+            .filter(|fields| {
+                let iyr = fields.get("iyr").unwrap().parse::<i64>().unwrap();
+                iyr >= 2010 && iyr <= 2020
+            })
+            .filter(|fields| {
+                let eyr = fields.get("eyr").unwrap().parse::<i64>().unwrap();
+                eyr >= 2020 && eyr <= 2030
+            })
+            .filter(|fields| {
+                let hgt = fields.get("hgt").unwrap();
+                if hgt_in_regex.is_match(hgt) {
+                    let hgt_num = hgt[..hgt.len() - 2].parse::<i64>().unwrap();
+                    hgt_num >= 59 && hgt_num <= 76
+                } else if hgt_cm_regex.is_match(hgt) {
+                    let hgt_num = hgt[..hgt.len() - 2].parse::<i64>().unwrap();
+                    hgt_num >= 150 && hgt_num <= 193
+                } else {
+                    false
+                }
+            })
+            .filter(|fields| {
+                let hcl = fields.get("hcl").unwrap();
+                hcl_rgx.is_match(hcl)
+            })
+            .filter(|fields| {
+                let ecl = fields.get("ecl").unwrap();
+                ecl_rgx.is_match(ecl)
+            })
+            .filter(|fields| {
+                let pid = fields.get("pid").unwrap();
+                pid_rgx.is_match(pid)
+            })
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day4;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let input = Day4::parse(Source::file("1_test.txt")).unwrap();
+        let ret = Day4::part_one(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 2);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "2_test.txt";
+        let input = Day4::parse(Source::file(test_input)).unwrap();
+        let ret = Day4::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 4);
+    }
+}
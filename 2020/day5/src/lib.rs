@@ -0,0 +1,74 @@
+use aoc_common::{Solution, Source};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    NotFound,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<String>;
+    type Config = ();
+    type PartOne = isize;
+    type PartTwo = isize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<String>, Error> {
+        source
+            .lines()
+            .map(|l| l.map_err(Error::IOError))
+            .into_iter()
+            .collect()
+    }
+
+    fn part_one(input: &Vec<String>, _: &()) -> Result<isize, Error> {
+        Ok(input.iter().fold(0, |s, l| {
+            let (_, _, seat_id) = seat_id(l);
+            if seat_id > s {
+                seat_id
+            } else {
+                s
+            }
+        }))
+    }
+
+    fn part_two(input: &Vec<String>, _: &()) -> Result<isize, Error> {
+        let mut seats: Vec<isize> = input.iter().map(|l| seat_id(l).2).collect();
+        seats.sort();
+        let first = seats[0];
+        for (idx, &seat) in seats.iter().enumerate() {
+            if first + idx as isize != seat {
+                return Ok(first + idx as isize);
+            }
+        }
+
+        Err(Error::NotFound)
+    }
+}
+
+fn seat_id(s: &str) -> (isize, isize, isize) {
+    let mut num: String = s.to_string();
+    num = num.replace("B", "1");
+    num = num.replace("F", "0");
+    num = num.replace("R", "1");
+    num = num.replace("L", "0");
+    let seat = isize::from_str_radix(&num, 2).unwrap();
+    let row = seat >> 3;
+    let col = seat & 7;
+    (row, col, seat)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::seat_id;
+
+    #[test]
+    fn test_seat_id() {
+        assert_eq!(seat_id("BFFFBBFRRR"), (70, 7, 567));
+        assert_eq!(seat_id("FFFBBBFRRR"), (14, 7, 119));
+        assert_eq!(seat_id("BBFFBBFRLL"), (102, 4, 820));
+    }
+}
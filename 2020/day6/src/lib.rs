@@ -0,0 +1,71 @@
+use aoc_common::{Sections, Solution, Source};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Vec<Vec<char>>>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<Vec<Vec<char>>>, Error> {
+        Ok(Sections::read(source)
+            .map_err(Error::Input)?
+            .iter()
+            .map(|group| group.iter().map(|p| p.chars().collect()).collect())
+            .collect())
+    }
+
+    fn part_one(input: &Vec<Vec<Vec<char>>>, _: &()) -> Result<usize, Error> {
+        Ok(input.iter().fold(0, |sum, group| {
+            let mut questions: HashSet<char> = HashSet::new();
+            group.iter().flatten().for_each(|&question| {
+                questions.insert(question);
+            });
+            sum + questions.len()
+        }))
+    }
+
+    fn part_two(input: &Vec<Vec<Vec<char>>>, _: &()) -> Result<usize, Error> {
+        Ok(input.iter().fold(0, |sum, group| {
+            let mut questions = HashMap::new();
+            group
+                .iter()
+                .flatten()
+                .for_each(|question| *questions.entry(question).or_insert(0) += 1);
+            sum + questions.iter().filter(|(_, &v)| v == group.len()).count()
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day6;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day6::parse(Source::file(test_input)).unwrap();
+        let ret = Day6::part_one(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 11);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day6::parse(Source::file(test_input)).unwrap();
+        let ret = Day6::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 6);
+    }
+}
@@ -0,0 +1,132 @@
+use aoc_common::{graph, Diagnostic, Solution, Source};
+use regex::Regex;
+use std::collections::HashMap;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    ParseLine(Diagnostic),
+    RegexError(regex::Error),
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = HashMap<String, Vec<(String, usize)>>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<HashMap<String, Vec<(String, usize)>>, Error> {
+        let path = source.name().to_string();
+        let mut bags_can_contain_rules: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+        let lines = source.lines();
+        let rgx = Regex::new(r"([0-9]+) ([a-z]+ [a-z]+) bag").map_err(Error::RegexError)?;
+        for (idx, maybe_line) in lines.enumerate() {
+            let line = maybe_line.map_err(Error::IOError)?;
+            let diagnostic = |token: &str, message: &str| {
+                Error::ParseLine(Diagnostic::new(&path, idx + 1, &line, token, message))
+            };
+            let mut inner_bags: Vec<(String, usize)> = Vec::new();
+            let (outer_bag, inner_bags_str) = line
+                .split_once(" bags contain ")
+                .ok_or_else(|| diagnostic(&line, "expected \"<colour> bags contain ...\""))?;
+
+            if inner_bags_str != "no other bags." {
+                for inner_bag_str in inner_bags_str.split(", ") {
+                    let cap = rgx.captures(inner_bag_str).ok_or_else(|| {
+                        diagnostic(inner_bag_str, "expected \"<n> <colour> bags\"")
+                    })?;
+                    let count = &cap[1];
+                    inner_bags.push((
+                        cap[2].to_owned(),
+                        count
+                            .parse::<usize>()
+                            .map_err(|e| diagnostic(count, &e.to_string()))?,
+                    ));
+                }
+            }
+
+            bags_can_contain_rules.insert(outer_bag.to_owned(), inner_bags);
+        }
+
+        Ok(bags_can_contain_rules)
+    }
+
+    fn part_one(rules: &HashMap<String, Vec<(String, usize)>>, _: &()) -> Result<usize, Error> {
+        // every bag reachable from shiny gold by going out a bag at a time,
+        // other than shiny gold itself
+        let inverted_rules = invert_rules(rules);
+        let bags = graph::bfs(["shiny gold".to_string()], |bag: &String| {
+            inverted_rules
+                .get(bag)
+                .into_iter()
+                .flatten()
+                .map(|(outer_bag, _)| outer_bag.clone())
+                .collect::<Vec<String>>()
+        });
+
+        Ok(bags.len() - 1)
+    }
+
+    fn part_two(rules: &HashMap<String, Vec<(String, usize)>>, _: &()) -> Result<usize, Error> {
+        let count = count_inner_bags("shiny gold", &rules);
+
+        Ok(count)
+    }
+}
+
+fn count_inner_bags(outer_bag: &str, rules: &HashMap<String, Vec<(String, usize)>>) -> usize {
+    if !rules.contains_key(outer_bag) {
+        return 0;
+    }
+
+    rules[outer_bag]
+        .iter()
+        .fold(0, |c, (inner_bag_color, inner_bag_count)| {
+            c + inner_bag_count + inner_bag_count * count_inner_bags(inner_bag_color, rules)
+        })
+}
+
+fn invert_rules(
+    bags_can_contain_rules: &HashMap<String, Vec<(String, usize)>>,
+) -> HashMap<String, Vec<(String, usize)>> {
+    let mut bags_can_be_contained_by_rules: HashMap<String, Vec<(String, usize)>> = HashMap::new();
+
+    for (outer_bag, inner_bags) in bags_can_contain_rules {
+        for (inner_bag_color, inner_bag_count) in inner_bags {
+            bags_can_be_contained_by_rules
+                .entry(inner_bag_color.clone())
+                .or_insert(Vec::new())
+                .push((outer_bag.clone(), *inner_bag_count));
+        }
+    }
+
+    bags_can_be_contained_by_rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day7;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day7::parse(Source::file(test_input)).unwrap();
+        let ret = Day7::part_one(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 4);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day7::parse(Source::file(test_input)).unwrap();
+        let ret = Day7::part_two(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 32);
+    }
+}
@@ -0,0 +1,134 @@
+use aoc_common::{Diagnostic, Solution, Source};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    ParseLine(Diagnostic),
+    UnknownInstruction,
+    SolutionNotFound,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Vec<(String, i64)>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<(String, i64)>, Error> {
+        let path = source.name().to_string();
+        source
+            .lines()
+            .enumerate()
+            .map(|(idx, maybe_line)| -> Result<(String, i64), Error> {
+                let line = maybe_line.map_err(Error::IOError)?;
+                let diagnostic = |token: &str, message: &str| {
+                    Error::ParseLine(Diagnostic::new(&path, idx + 1, &line, token, message))
+                };
+                let (instruction, value) = line
+                    .split_once(" ")
+                    .ok_or_else(|| diagnostic(&line, "expected \"<operation> <argument>\""))?;
+                Ok((
+                    instruction.to_string(),
+                    value
+                        .parse::<i64>()
+                        .map_err(|e| diagnostic(value, &e.to_string()))?,
+                ))
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn part_one(codes: &Vec<(String, i64)>, _: &()) -> Result<i64, Error> {
+        let (accumulator, _) = run(&codes)?;
+        Ok(accumulator)
+    }
+
+    fn part_two(input: &Vec<(String, i64)>, _: &()) -> Result<i64, Error> {
+        let codes = &mut input.clone();
+
+        for idx in 0..codes.len() {
+            let (instruction, value) = codes[idx].clone();
+            let new_instruction = if instruction == "nop" {
+                "jmp"
+            } else if instruction == "jmp" {
+                "nop"
+            } else {
+                continue;
+            };
+
+            // replace instruction
+            codes[idx] = (new_instruction.to_string(), value);
+
+            let (accumulator, terminates) = run(codes)?;
+            if terminates {
+                return Ok(accumulator);
+            }
+
+            // put instruction back
+            codes[idx] = (instruction, value);
+        }
+
+        Err(Error::SolutionNotFound)
+    }
+}
+
+fn run(codes: &Vec<(String, i64)>) -> Result<(i64, bool), Error> {
+    let mut accumulator = 0;
+    let mut idx = 0;
+    let mut visited_idxs: HashSet<i64> = HashSet::new();
+    let mut terminates = false;
+    loop {
+        if visited_idxs.contains(&idx) {
+            break;
+        }
+
+        if idx == codes.len() as i64 {
+            terminates = true;
+            break;
+        }
+
+        let (instruction, value) = &codes[idx as usize];
+        visited_idxs.insert(idx);
+        if instruction == "nop" {
+            idx += 1;
+        } else if instruction == "acc" {
+            idx += 1;
+            accumulator += value;
+        } else if instruction == "jmp" {
+            idx += value;
+        } else {
+            return Err(Error::UnknownInstruction);
+        }
+    }
+
+    Ok((accumulator, terminates))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day8;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day8::parse(Source::file(test_input)).unwrap();
+        let ret = Day8::part_one(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 5);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day8::parse(Source::file(test_input)).unwrap();
+        let ret = Day8::part_two(&input, &());
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 8);
+    }
+}
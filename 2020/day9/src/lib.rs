@@ -0,0 +1,154 @@
+use aoc_common::{input, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    SolutionNotFound,
+}
+
+pub struct Config {
+    pub window: usize,
+    // the invalid number from part one is used when no goal is given
+    pub goal: Option<i64>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            window: 25,
+            goal: None,
+        }
+    }
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<i64>;
+    type Config = Config;
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<i64>, Error> {
+        input::parse_int_per_line::<i64>(source).map_err(Error::Input)
+    }
+
+    fn part_one(numbers: &Vec<i64>, config: &Config) -> Result<i64, Error> {
+        find_invalid(numbers, config.window)
+    }
+
+    fn part_two(numbers: &Vec<i64>, config: &Config) -> Result<i64, Error> {
+        let goal = match config.goal {
+            Some(goal) => goal,
+            None => find_invalid(numbers, config.window)?,
+        };
+
+        find_weakness(numbers, goal)
+    }
+}
+
+fn find_invalid(numbers: &[i64], window: usize) -> Result<i64, Error> {
+    for idx in window..numbers.len() {
+        let number = numbers[idx];
+        let previous_numbers = &numbers[(idx - window)..idx];
+
+        let mut found = false;
+        'outer: for i in 0..window {
+            for j in i + 1..window {
+                if previous_numbers[i] + previous_numbers[j] == number {
+                    found = true;
+                    break 'outer;
+                }
+            }
+        }
+
+        if !found {
+            return Ok(number);
+        }
+    }
+
+    Err(Error::SolutionNotFound)
+}
+
+fn find_weakness(numbers: &[i64], goal: i64) -> Result<i64, Error> {
+    let mut range_start = 0;
+    let mut range_end = 0;
+
+    'outer: for i in 0..numbers.len() {
+        let mut sum = numbers[i];
+        for j in i + 1..numbers.len() {
+            sum += numbers[j];
+            if sum > goal {
+                break;
+            } else if sum == goal {
+                range_start = i;
+                range_end = j;
+                break 'outer;
+            }
+        }
+    }
+
+    if range_end == range_start {
+        return Err(Error::SolutionNotFound);
+    }
+
+    let mut smallest = numbers[range_start];
+    let mut largest = numbers[range_start];
+
+    for i in range_start + 1..range_end {
+        let num = numbers[i];
+        if num < smallest {
+            smallest = num;
+        } else if num > largest {
+            largest = num;
+        }
+    }
+
+    Ok(smallest + largest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Config, Day9};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day9::parse(Source::file(test_input)).unwrap();
+        let config = Config {
+            window: 5,
+            goal: None,
+        };
+        let ret = Day9::part_one(&input, &config);
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 127);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day9::parse(Source::file(test_input)).unwrap();
+        let config = Config {
+            window: 5,
+            goal: Some(127),
+        };
+        let ret = Day9::part_two(&input, &config);
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 62);
+    }
+
+    #[test]
+    fn test_part_two_without_goal() {
+        let input = Day9::parse(Source::file("1_test.txt")).unwrap();
+        let config = Config {
+            window: 5,
+            goal: None,
+        };
+        let ret = Day9::part_two(&input, &config);
+
+        assert!(ret.is_ok());
+        assert_eq!(ret.unwrap(), 62);
+    }
+}
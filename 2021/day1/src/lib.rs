@@ -0,0 +1,66 @@
+use aoc_common::{input, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<i64>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<i64>, Error> {
+        input::parse_int_per_line::<i64>(source).map_err(Error::Input)
+    }
+
+    fn part_one(depths: &Vec<i64>, _: &()) -> Result<usize, Error> {
+        return Ok(depths
+            .iter()
+            .zip(depths.iter().skip(1))
+            .filter(|&(l, r)| l < r)
+            .count());
+    }
+
+    fn part_two(depths: &Vec<i64>, _: &()) -> Result<usize, Error> {
+        let sums = depths
+            .iter()
+            .zip(depths.iter().skip(1))
+            .zip(depths.iter().skip(2))
+            .map(&|((a, b), c)| a + b + c);
+
+        return Ok(sums
+            .clone()
+            .zip(sums.clone().skip(1))
+            .filter(|&(l, r)| l < r)
+            .count());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day1;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "input_part_one_test.txt";
+        let input = Day1::parse(Source::file(test_input)).unwrap();
+        let ret = Day1::part_one(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 7);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "input_part_two_test.txt";
+        let input = Day1::parse(Source::file(test_input)).unwrap();
+        let ret = Day1::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 5);
+    }
+}
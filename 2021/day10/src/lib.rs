@@ -0,0 +1,166 @@
+use aoc_common::{Diagnostic, Solution, Source};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    InvalidToken(Diagnostic),
+    UnexpectedToken(Token),
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Token {
+    LeftAngle,
+    LeftBrace,
+    LeftBracket,
+    LeftParen,
+    RightAngle,
+    RightBrace,
+    RightBracket,
+    RightParen,
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Vec<Token>>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<Vec<Token>>, Error> {
+        let path = source.name().to_string();
+        source
+            .lines()
+            .enumerate()
+            .map(|(idx, maybe_line)| -> Result<Vec<Token>, Error> {
+                let line = maybe_line.map_err(Error::IO)?;
+
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| match c {
+                        '(' => Ok(Token::LeftParen),
+                        ')' => Ok(Token::RightParen),
+                        '[' => Ok(Token::LeftBracket),
+                        ']' => Ok(Token::RightBracket),
+                        '{' => Ok(Token::LeftBrace),
+                        '}' => Ok(Token::RightBrace),
+                        '<' => Ok(Token::LeftAngle),
+                        '>' => Ok(Token::RightAngle),
+                        _ => Err(Error::InvalidToken(Diagnostic::at(
+                            &path,
+                            idx + 1,
+                            &line,
+                            col + 1,
+                            1,
+                            format!("unexpected character {:?}", c),
+                        ))),
+                    })
+                    .into_iter()
+                    .collect::<Result<Vec<Token>, Error>>()
+            })
+            .collect::<Result<Vec<Vec<Token>>, Error>>()
+    }
+
+    fn part_one(input: &Vec<Vec<Token>>, _: &()) -> Result<i64, Error> {
+        let mut failed_tokens: Vec<Token> = Vec::new();
+        for line in input {
+            let (token, _) = scan_line(&line);
+            if token.is_some() {
+                failed_tokens.push(token.unwrap());
+            }
+        }
+
+        let mut score = 0;
+        for token in failed_tokens {
+            score += match token {
+                Token::RightParen => 3,
+                Token::RightBracket => 57,
+                Token::RightBrace => 1197,
+                Token::RightAngle => 25137,
+                _ => return Err(Error::UnexpectedToken(token)),
+            }
+        }
+
+        Ok(score)
+    }
+
+    fn part_two(input: &Vec<Vec<Token>>, _: &()) -> Result<i64, Error> {
+        let mut scores: Vec<i64> = Vec::new();
+
+        for line in input {
+            let (invalid_token, stack) = scan_line(&line);
+            if invalid_token.is_some() {
+                continue;
+            }
+
+            scores.push(stack.iter().rev().fold(0, |s, token| {
+                s * 5 + {
+                    match token {
+                        Token::LeftParen => 1,
+                        Token::LeftBracket => 2,
+                        Token::LeftBrace => 3,
+                        Token::LeftAngle => 4,
+                        _ => unreachable!(),
+                    }
+                }
+            }));
+        }
+
+        scores.sort();
+
+        Ok(scores[scores.len() / 2])
+    }
+}
+
+fn scan_line(line: &Vec<Token>) -> (Option<Token>, Vec<Token>) {
+    let mut stack: Vec<Token> = Vec::new();
+    for &token in line {
+        match token {
+            Token::LeftAngle => stack.push(token),
+            Token::LeftBrace => stack.push(token),
+            Token::LeftBracket => stack.push(token),
+            Token::LeftParen => stack.push(token),
+            Token::RightAngle => {
+                if stack.pop() != Some(Token::LeftAngle) {
+                    return (Some(token), stack);
+                }
+            }
+            Token::RightBrace => {
+                if stack.pop() != Some(Token::LeftBrace) {
+                    return (Some(token), stack);
+                }
+            }
+            Token::RightBracket => {
+                if stack.pop() != Some(Token::LeftBracket) {
+                    return (Some(token), stack);
+                }
+            }
+            Token::RightParen => {
+                if stack.pop() != Some(Token::LeftParen) {
+                    return (Some(token), stack);
+                }
+            }
+        };
+    }
+    (None, stack)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day10;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let res = Day10::part_one(&Day10::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 26397);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let res = Day10::part_two(&Day10::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 288957);
+    }
+}
@@ -0,0 +1,119 @@
+use aoc_common::grid::Pos;
+#[cfg(feature = "render")]
+use aoc_common::render::{Animated, Frame, Recorder};
+use aoc_common::{Grid, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+pub struct Day11;
+
+// raises every octopus's energy by one, then lets octopuses above 9 flash,
+// raising their neighbours' energy in turn. Returns how many flashed.
+fn step(octopuses: &mut Grid<i64>) -> usize {
+    let mut flashing: Vec<Pos> = Vec::new();
+    for (pos, energy) in octopuses.positions().zip(octopuses.cells_mut()) {
+        *energy += 1;
+        if *energy > 9 {
+            flashing.push(pos);
+        }
+    }
+
+    // an octopus flashes when its energy first passes 9, so it can't be added twice
+    let mut flashed = 0;
+    while let Some(pos) = flashing.pop() {
+        flashed += 1;
+        let neighbours: Vec<Pos> = octopuses.neighbours8(pos).collect();
+        for next in neighbours {
+            octopuses[next] += 1;
+            if octopuses[next] == 10 {
+                flashing.push(next);
+            }
+        }
+    }
+
+    for energy in octopuses.cells_mut() {
+        if *energy > 9 {
+            *energy = 0;
+        }
+    }
+
+    flashed
+}
+
+impl Solution for Day11 {
+    type Input = Grid<i64>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Grid<i64>, Error> {
+        Grid::parse(source, |c| c.to_digit(10).map(i64::from)).map_err(Error::Input)
+    }
+
+    fn part_one(input: &Grid<i64>, _: &()) -> Result<i64, Error> {
+        let mut octopuses = input.clone();
+
+        // model 100 steps
+        let total_flashes: usize = (0..100).map(|_| step(&mut octopuses)).sum();
+
+        Ok(total_flashes as i64)
+    }
+
+    fn part_two(input: &Grid<i64>, _: &()) -> Result<i64, Error> {
+        let mut octopuses = input.clone();
+        let count = octopuses.width() * octopuses.height();
+
+        let mut steps = 1;
+        while step(&mut octopuses) != count {
+            steps += 1;
+        }
+
+        Ok(steps)
+    }
+}
+
+// each octopus shaded by its energy, with those that just flashed in white
+#[cfg(feature = "render")]
+impl Animated for Day11 {
+    fn record(input: &Grid<i64>, part: u8) -> Recorder {
+        let mut palette = vec![[255, 255, 255]];
+        palette.extend((1..10).map(|energy| [0, 10 + 12 * energy, 40 + 20 * energy]));
+        let mut recorder = Recorder::new(&palette).scale(16);
+
+        let mut octopuses = input.clone();
+        let count = octopuses.width() * octopuses.height();
+        let frame = |octopuses: &Grid<i64>| Frame::grid(octopuses, |&energy| energy as u8);
+
+        recorder.push(frame(&octopuses));
+        for steps in 1.. {
+            let flashed = step(&mut octopuses);
+            recorder.push(frame(&octopuses));
+            if (part == 1 && steps == 100) || (part != 1 && flashed == count) {
+                break;
+            }
+        }
+        recorder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day11;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let res = Day11::part_one(&Day11::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 1656);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let res = Day11::part_two(&Day11::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 195);
+    }
+}
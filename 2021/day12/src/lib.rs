@@ -0,0 +1,174 @@
+use aoc_common::{graph, Diagnostic, Solution, Source};
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    Parse(Diagnostic),
+    // big caves linked to each other can be gone round forever
+    EndlessPaths(Vec<Cave>),
+}
+
+#[derive(Debug, PartialEq, Hash, Eq, Clone, Copy)]
+pub enum Cave {
+    Start,
+    End,
+    Big([char; 2]),
+    Small([char; 2]),
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HashMap<Cave, HashSet<Cave>>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<HashMap<Cave, HashSet<Cave>>, Error> {
+        let path = source.name().to_string();
+        let mut caves: HashMap<Cave, HashSet<Cave>> = HashMap::new();
+        for res in source
+            .lines()
+            .enumerate()
+            .map(|(idx, maybe_line)| -> Result<(Cave, Cave), Error> {
+                let line = maybe_line.map_err(Error::IO)?;
+                let diagnostic = |token: &str, message: &str| {
+                    Error::Parse(Diagnostic::new(&path, idx + 1, &line, token, message))
+                };
+                let (left, right) = line
+                    .split_once("-")
+                    .ok_or_else(|| diagnostic(&line, "expected \"<cave>-<cave>\""))?;
+
+                let parse = |part: &str| -> Result<Cave, Error> {
+                    let c1 = part
+                        .chars()
+                        .next()
+                        .ok_or_else(|| diagnostic(part, "empty cave name"))?;
+                    let c2 = part.chars().skip(1).next().unwrap_or(' ');
+                    match part {
+                        "start" => Ok(Cave::Start),
+                        "end" => Ok(Cave::End),
+                        _ if part.to_uppercase() == part => Ok(Cave::Big([c1, c2])),
+                        _ if part.to_lowercase() == part => Ok(Cave::Small([c1, c2])),
+                        _ => Err(diagnostic(
+                            part,
+                            "cave name must be all upper or lower case",
+                        )),
+                    }
+                };
+
+                let from = parse(left)?;
+                let to = parse(right)?;
+
+                Ok((from, to))
+            })
+            .into_iter()
+        {
+            let (from, to) = res?;
+
+            // insert from => to
+            if to != Cave::Start && from != Cave::End {
+                (*caves.entry(from).or_insert(HashSet::new())).insert(to);
+            }
+
+            if to != Cave::End && from != Cave::Start {
+                // insert reverse relation
+                (*caves.entry(to).or_insert(HashSet::new())).insert(from);
+            }
+        }
+
+        Ok(caves)
+    }
+
+    fn part_one(input: &HashMap<Cave, HashSet<Cave>>, _: &()) -> Result<usize, Error> {
+        count_paths_to_end(input, false)
+    }
+
+    fn part_two(input: &HashMap<Cave, HashSet<Cave>>, _: &()) -> Result<usize, Error> {
+        count_paths_to_end(input, true)
+    }
+}
+
+// where a path has got to: the cave it is in, the small caves it has been
+// through, and whether it has been through one of them twice
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Route {
+    cave: Cave,
+    visited: BTreeSet<[char; 2]>,
+    revisited: bool,
+}
+
+fn count_paths_to_end(
+    connections: &HashMap<Cave, HashSet<Cave>>,
+    may_revisit: bool,
+) -> Result<usize, Error> {
+    let start = Route {
+        cave: Cave::Start,
+        visited: BTreeSet::new(),
+        revisited: !may_revisit,
+    };
+
+    let next_routes = |route: &Route| -> Vec<Route> {
+        connections
+            .get(&route.cave)
+            .into_iter()
+            .flatten()
+            .filter_map(|&cave| {
+                let mut next = Route {
+                    cave,
+                    ..route.clone()
+                };
+                if let Cave::Small(name) = cave {
+                    if !next.visited.insert(name) {
+                        if next.revisited {
+                            return None;
+                        }
+                        next.revisited = true;
+                    }
+                }
+                Some(next)
+            })
+            .collect()
+    };
+
+    graph::count_paths(start, next_routes, |route| route.cave == Cave::End)
+        .map_err(|cycle| Error::EndlessPaths(cycle.into_iter().map(|r| r.cave).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Day12, Error};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let res = Day12::part_one(&Day12::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 10);
+        let res = Day12::part_one(&Day12::parse(Source::file("2_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 19);
+        let res = Day12::part_one(&Day12::parse(Source::file("3_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 226);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let res = Day12::part_two(&Day12::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 36);
+        let res = Day12::part_two(&Day12::parse(Source::file("2_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 103);
+        let res = Day12::part_two(&Day12::parse(Source::file("3_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 3509);
+    }
+
+    #[test]
+    fn test_endless_paths() {
+        let input = Day12::parse(Source::text("start-A\nA-B\nB-end\n")).unwrap();
+        match Day12::part_one(&input, &()) {
+            Err(Error::EndlessPaths(caves)) => assert_eq!(caves.len(), 2),
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use aoc_common::{ocr, Diagnostic, Sections, Solution, Source};
+use regex::Regex;
+use std::collections::HashSet;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    Parse(Diagnostic),
+    Regex(regex::Error),
+    Ocr(aoc_common::Error),
+}
+
+#[derive(Debug)]
+pub enum Fold {
+    Y(i64),
+    X(i64),
+}
+
+#[derive(Debug)]
+pub struct Instructions {
+    dots: Vec<(i64, i64)>,
+    folds: Vec<Fold>,
+}
+
+pub struct Config {
+    pub part_one_folds: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { part_one_folds: 1 }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Instructions;
+    type Config = Config;
+    type PartOne = usize;
+    type PartTwo = String;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Instructions, Error> {
+        parse_input(source)
+    }
+
+    fn part_one(input: &Instructions, config: &Config) -> Result<usize, Error> {
+        Ok(fold(input, config.part_one_folds).len())
+    }
+
+    fn part_two(input: &Instructions, _: &Config) -> Result<String, Error> {
+        ocr::read_points(&fold(input, input.folds.len())).map_err(Error::Ocr)
+    }
+}
+
+fn fold(input: &Instructions, num_folds: usize) -> HashSet<(i64, i64)> {
+    let mut paper: HashSet<(i64, i64)> = HashSet::new();
+
+    for &(x, y) in input.dots.iter() {
+        paper.insert((x, y));
+    }
+
+    for fold in input.folds.iter().take(num_folds) {
+        let updated_paper: HashSet<(i64, i64)> =
+            HashSet::from_iter(paper.iter().map(|&(x, y)| match fold {
+                Fold::X(fold_x) => (
+                    if x < *fold_x {
+                        x
+                    } else {
+                        fold_x - (x - fold_x)
+                    },
+                    y,
+                ),
+                Fold::Y(fold_y) => (
+                    x,
+                    if y < *fold_y {
+                        y
+                    } else {
+                        fold_y - (y - fold_y)
+                    },
+                ),
+            }));
+        paper = updated_paper;
+    }
+
+    paper
+}
+
+fn parse_input(source: Source) -> Result<Instructions, Error> {
+    let sections = Sections::read(source)
+        .map_err(Error::Input)?
+        .expect(2)
+        .map_err(Error::Input)?;
+    let (top, bottom) = (&sections[0], &sections[1]);
+    let diagnostic = |section: usize, line: usize, token: &str, message: &str| {
+        Error::Parse(sections.diagnostic(section, line, token, message))
+    };
+
+    let x_fold_rgx = Regex::new(r"fold along x=([0-9]+)").map_err(Error::Regex)?;
+    let y_fold_rgx = Regex::new(r"fold along y=([0-9]+)").map_err(Error::Regex)?;
+
+    let parse_fold = |rgx: &Regex, idx: usize, line: &str| -> Result<Option<i64>, Error> {
+        match rgx.captures(line) {
+            Some(captures) => captures[1]
+                .parse::<i64>()
+                .map(Some)
+                .map_err(|e| diagnostic(1, idx, &captures[1], &e.to_string())),
+            None => Ok(None),
+        }
+    };
+
+    Ok(Instructions {
+        dots: top
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| -> Result<(i64, i64), Error> {
+                let (left, right) = line
+                    .split_once(",")
+                    .ok_or_else(|| diagnostic(0, idx, line, "expected \"<x>,<y>\""))?;
+                let parse_num = |s: &str| {
+                    s.parse::<i64>()
+                        .map_err(|e| diagnostic(0, idx, s, &e.to_string()))
+                };
+
+                Ok((parse_num(left)?, parse_num(right)?))
+            })
+            .into_iter()
+            .collect::<Result<Vec<(i64, i64)>, Error>>()?,
+        folds: bottom
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| -> Result<Fold, Error> {
+                if let Some(x) = parse_fold(&x_fold_rgx, idx, line)? {
+                    Ok(Fold::X(x))
+                } else if let Some(y) = parse_fold(&y_fold_rgx, idx, line)? {
+                    Ok(Fold::Y(y))
+                } else {
+                    Err(diagnostic(
+                        1,
+                        idx,
+                        line,
+                        "expected \"fold along <x|y>=<n>\"",
+                    ))
+                }
+            })
+            .into_iter()
+            .collect::<Result<Vec<Fold>, Error>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fold, Day13};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let input = Day13::parse(Source::file("test_1.txt")).unwrap();
+        assert_eq!(fold(&input, 100).len(), 16);
+        let res = Day13::part_one(&input, &Default::default());
+        assert_eq!(res.unwrap(), 17);
+    }
+}
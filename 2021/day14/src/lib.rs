@@ -0,0 +1,161 @@
+use aoc_common::number::Count;
+use aoc_common::{Diagnostic, Sections, Solution, Source};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    Parse(Diagnostic),
+    MissingRule((char, char)),
+    Count(aoc_common::Error),
+}
+
+#[derive(Debug)]
+pub struct Input {
+    template: Vec<char>,
+    rules: HashMap<(char, char), char>,
+}
+
+pub struct Config {
+    pub part_one_steps: usize,
+    pub part_two_steps: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_one_steps: 10,
+            part_two_steps: 40,
+        }
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Input;
+    type Config = Config;
+    type PartOne = Count;
+    type PartTwo = Count;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Input, Error> {
+        parse_input(source)
+    }
+
+    fn part_one(input: &Input, config: &Config) -> Result<Count, Error> {
+        polymerize(input, config.part_one_steps)
+    }
+
+    fn part_two(input: &Input, config: &Config) -> Result<Count, Error> {
+        polymerize(input, config.part_two_steps)
+    }
+}
+
+fn polymerize(input: &Input, steps: usize) -> Result<Count, Error> {
+    let mut counts: HashMap<char, Count> = HashMap::new();
+    for i in 0..input.template.len() {
+        add(&mut counts, input.template[i], &Count::from(1))?;
+    }
+
+    let mut polymer_pairs: HashMap<(char, char), Count> = HashMap::new();
+    for i in 0..input.template.len() - 1 {
+        let a = input.template[i];
+        let b = input.template[i + 1];
+        add(&mut polymer_pairs, (a, b), &Count::from(1))?;
+    }
+
+    for _ in 0..steps {
+        let mut updated_polymer_pairs: HashMap<(char, char), Count> = HashMap::new();
+
+        for (pair, count) in polymer_pairs.iter() {
+            let insertion = *input.rules.get(pair).ok_or(Error::MissingRule(*pair))?;
+
+            add(&mut updated_polymer_pairs, (pair.0, insertion), count)?;
+            add(&mut updated_polymer_pairs, (insertion, pair.1), count)?;
+            add(&mut counts, insertion, count)?;
+        }
+
+        polymer_pairs = updated_polymer_pairs;
+    }
+
+    let least_common_count = counts.values().min().unwrap();
+    let most_common_count = counts.values().max().unwrap();
+
+    most_common_count
+        .checked_sub(least_common_count)
+        .map_err(Error::Count)
+}
+
+fn add<K: Eq + Hash>(counts: &mut HashMap<K, Count>, key: K, count: &Count) -> Result<(), Error> {
+    let total = counts.entry(key).or_default();
+    *total = total.checked_add(count).map_err(Error::Count)?;
+    Ok(())
+}
+
+fn parse_input(source: Source) -> Result<Input, Error> {
+    let sections = Sections::read(source)
+        .map_err(Error::Input)?
+        .expect(2)
+        .map_err(Error::Input)?;
+    let (top, bottom) = (&sections[0], &sections[1]);
+
+    Ok(Input {
+        template: top.concat().chars().collect(),
+        rules: bottom
+            .iter()
+            .enumerate()
+            .map(|(idx, line)| -> Result<((char, char), char), Error> {
+                let chars: Vec<char> = line.chars().collect();
+
+                match chars[..] {
+                    [a, b, ' ', '-', '>', ' ', c] => Ok(((a, b), c)),
+                    _ => Err(Error::Parse(sections.diagnostic(
+                        1,
+                        idx,
+                        line,
+                        "expected \"AB -> C\"",
+                    ))),
+                }
+            })
+            .into_iter()
+            .collect::<Result<HashMap<(char, char), char>, Error>>()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{polymerize, Day14};
+    use aoc_common::number::Count;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let input = Day14::parse(Source::file("test_1.txt")).unwrap();
+        let res = Day14::part_one(&input, &Default::default());
+        assert_eq!(res.unwrap(), Count::from(1588));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day14::parse(Source::file("test_1.txt")).unwrap();
+        let res = Day14::part_two(&input, &Default::default());
+        assert_eq!(res.unwrap(), Count::from(2188189693529));
+    }
+
+    #[test]
+    fn test_many_steps() {
+        let input = Day14::parse(Source::file("test_1.txt")).unwrap();
+        let res = polymerize(&input, 100);
+
+        #[cfg(not(feature = "bigint"))]
+        assert!(matches!(
+            res,
+            Err(super::Error::Count(aoc_common::Error::Arithmetic(_)))
+        ));
+        // the polymer is 3 * 2^100 + 1 long by now, about 31 digits
+        #[cfg(feature = "bigint")]
+        assert!(res.unwrap().to_string().len() > 25);
+    }
+}
@@ -0,0 +1,100 @@
+use aoc_common::{Diagnostic, Solution, Source};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    ParseLineError(Diagnostic),
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<(i64, i64)>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<(i64, i64)>, Error> {
+        let path = source.name().to_string();
+        source
+            .lines()
+            .enumerate()
+            .map(|(idx, line)| -> Result<(i64, i64), Error> {
+                let line = line.map_err(Error::IOError)?;
+                let diagnostic = |token: &str, message: &str| {
+                    Error::ParseLineError(Diagnostic::new(&path, idx + 1, &line, token, message))
+                };
+
+                line.split_once(" ")
+                    .ok_or_else(|| diagnostic(&line, "expected \"<direction> <distance>\""))
+                    .map(|(col_one, col_two)| -> Result<(&str, i64), Error> {
+                        Ok((
+                            col_one,
+                            col_two
+                                .parse::<i64>()
+                                .map_err(|e| diagnostic(col_two, &e.to_string()))?,
+                        ))
+                    })?
+                    .map(|(col_one, distance)| -> Result<(i64, i64), Error> {
+                        match col_one {
+                            "forward" => Ok((distance, 0)),
+                            "down" => Ok((0, distance)),
+                            "up" => Ok((0, -distance)),
+                            _ => Err(diagnostic(col_one, "unknown direction")),
+                        }
+                    })?
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn part_one(input: &Vec<(i64, i64)>, _: &()) -> Result<i64, Error> {
+        travel(input).map(|(x, z)| x * z)
+    }
+
+    fn part_two(input: &Vec<(i64, i64)>, _: &()) -> Result<i64, Error> {
+        travel_with_aim(input).map(|(x, z, _)| x * z)
+    }
+}
+
+pub fn travel_with_aim(input: &[(i64, i64)]) -> Result<(i64, i64, i64), Error> {
+    Ok(input
+        .iter()
+        .fold((0, 0, 0), |(x, z, aim), (dx, da)| -> (i64, i64, i64) {
+            (x + dx, z + dx * aim, aim + da)
+        }))
+}
+
+pub fn travel(input: &[(i64, i64)]) -> Result<(i64, i64), Error> {
+    Ok(input.iter().fold((0, 0), |(x, z), (dx, dz)| -> (i64, i64) {
+        (x + dx, z + dz)
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{travel, travel_with_aim, Day2};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let input = Day2::parse(Source::file("input_part_one_test.txt")).unwrap();
+        let ret = travel(&input);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (15, 10));
+        assert_eq!(Day2::part_one(&input, &()).unwrap(), 150);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = Day2::parse(Source::file("input_part_two_test.txt")).unwrap();
+        let ret = travel_with_aim(&input);
+        assert_eq!(ret.is_ok(), true);
+        let (x, z, _) = ret.unwrap();
+        assert_eq!(x, 15);
+        assert_eq!(z, 60);
+        assert_eq!(Day2::part_two(&input, &()).unwrap(), 900);
+    }
+}
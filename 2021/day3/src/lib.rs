@@ -0,0 +1,115 @@
+use aoc_common::{Solution, Source};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub enum Error {
+    IOError(std::io::Error),
+    ParseIntError(std::num::ParseIntError),
+    SolutionNotFound,
+}
+
+pub struct Config {
+    pub bits: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { bits: 12 }
+    }
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Vec<usize>;
+    type Config = Config;
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<usize>, Error> {
+        source
+            .lines()
+            .map(|line| -> Result<usize, Error> {
+                let num_str = line.map_err(Error::IOError)?;
+                usize::from_str_radix(&num_str, 2).map_err(Error::ParseIntError)
+            })
+            .into_iter()
+            .collect()
+    }
+
+    fn part_one(report: &Vec<usize>, config: &Config) -> Result<usize, Error> {
+        power_consumption(report, config.bits).map(|(_, _, power)| power)
+    }
+
+    fn part_two(report: &Vec<usize>, config: &Config) -> Result<usize, Error> {
+        life_support_rating(report, config.bits).map(|(_, _, rating)| rating)
+    }
+}
+
+pub fn power_consumption(report: &[usize], bits: usize) -> Result<(usize, usize, usize), Error> {
+    let gamma_rate = (0..bits)
+        .map(|i| 1 << i)
+        .filter(|m| report.iter().filter(|&n| (n & m) != 0).count() > report.len() / 2)
+        .fold(0, |s, m| s | m);
+
+    let epsilon_rate = (usize::MAX ^ gamma_rate) & ((1 << bits) - 1);
+
+    Ok((gamma_rate, epsilon_rate, gamma_rate * epsilon_rate))
+}
+
+fn find_candidate(
+    report: &[usize],
+    bits: usize,
+    filter: fn(isize, isize) -> bool,
+) -> Result<usize, Error> {
+    let mut candidates = report.to_vec();
+
+    for m in (0..bits).rev().map(|i| 1 << i) {
+        let ones = candidates.iter().filter(|&n| (n & m) != 0).count() as isize;
+        let filter_ones = filter(ones, candidates.len() as isize - ones);
+
+        candidates = candidates
+            .iter()
+            .filter(|&n| (n & m != 0) == filter_ones)
+            .map(|&n| n)
+            .collect();
+        if candidates.len() == 1 {
+            return Ok(candidates[0]);
+        } else if candidates.len() == 0 {
+            return Err(Error::SolutionNotFound);
+        }
+    }
+    Err(Error::SolutionNotFound)
+}
+
+pub fn life_support_rating(report: &[usize], bits: usize) -> Result<(usize, usize, usize), Error> {
+    let og = find_candidate(&report, bits, |ones, zeroes| ones >= zeroes)?;
+    let co2 = find_candidate(&report, bits, |ones, zeroes| ones < zeroes)?;
+
+    Ok((og, co2, og * co2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{life_support_rating, power_consumption, Config, Day3};
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let report = Day3::parse(Source::file("1_test.txt")).unwrap();
+        let ret = power_consumption(&report, 5);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (22, 9, 198));
+        assert_eq!(Day3::part_one(&report, &Config { bits: 5 }).unwrap(), 198);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let report = Day3::parse(Source::file("1_test.txt")).unwrap();
+        let ret = life_support_rating(&report, 5);
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), (23, 10, 230));
+        assert_eq!(Day3::part_two(&report, &Config { bits: 5 }).unwrap(), 230);
+    }
+}
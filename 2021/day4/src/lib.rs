@@ -0,0 +1,183 @@
+use aoc_common::{Diagnostic, Sections, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    Parse(Diagnostic),
+    NotImplemented,
+    WinerNotFound,
+}
+
+#[derive(Debug, Clone)]
+pub struct Board([[i64; 5]; 5]);
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = (Vec<i64>, Vec<Board>);
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<(Vec<i64>, Vec<Board>), Error> {
+        let sections = Sections::read(source).map_err(Error::Input)?;
+        if sections.len() < 2 {
+            return Err(Error::Input(aoc_common::Error::SectionCount {
+                expected: 2,
+                found: sections.len(),
+            }));
+        }
+
+        let numbers = parse_comma_separated_nums(&sections)?;
+        let mut boards: Vec<Board> = Vec::new();
+
+        for section in 1..sections.len() {
+            boards.push(parse_board(&sections, section)?);
+        }
+
+        Ok((numbers, boards))
+    }
+
+    fn part_one(input: &(Vec<i64>, Vec<Board>), _: &()) -> Result<i64, Error> {
+        let (numbers, boards) = input;
+        let mut boards = boards.clone();
+        for n in numbers.iter() {
+            for board in &mut boards {
+                let is_winner = board.remove_number(*n);
+                if is_winner {
+                    return Ok(board.umarked_sum() * n);
+                }
+            }
+        }
+
+        Err(Error::WinerNotFound)
+    }
+
+    fn part_two(input: &(Vec<i64>, Vec<Board>), _: &()) -> Result<i64, Error> {
+        let (numbers, boards) = input;
+        let mut boards = boards.clone();
+        for n in numbers.iter() {
+            let mut boards_to_remove: Vec<usize> = Vec::new();
+            let remaining = boards.len();
+            for b_idx in 0..remaining {
+                let board = &mut boards[b_idx];
+                let is_winner = board.remove_number(*n);
+
+                if is_winner {
+                    boards_to_remove.push(b_idx);
+                    if remaining == boards_to_remove.len() {
+                        return Ok(board.umarked_sum() * n);
+                    }
+                }
+            }
+
+            for idx in boards_to_remove.iter().rev() {
+                boards.remove(*idx);
+            }
+        }
+
+        Err(Error::WinerNotFound)
+    }
+}
+
+impl Board {
+    fn umarked_sum(&self) -> i64 {
+        let mut s = 0;
+        for i in 0..5 {
+            for j in 0..5 {
+                if self.0[i][j] != -1 {
+                    s += self.0[i][j];
+                }
+            }
+        }
+        s
+    }
+
+    fn remove_number(&mut self, n: i64) -> bool {
+        for i in 0..5 {
+            for j in 0..5 {
+                if n == self.0[i][j] {
+                    self.0[i][j] = -1;
+                    return self.row_complete(i) || self.col_complete(j);
+                }
+            }
+        }
+
+        false
+    }
+
+    fn row_complete(&self, i: usize) -> bool {
+        for j in 0..5 {
+            if self.0[i][j] != -1 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn col_complete(&self, j: usize) -> bool {
+        for i in 0..5 {
+            if self.0[i][j] != -1 {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+fn parse_board(sections: &Sections, section: usize) -> Result<Board, Error> {
+    let mut board = [[0i64; 5]; 5];
+    for (i, row) in sections[section].iter().enumerate() {
+        for (j, c) in row
+            .split(' ')
+            .map(|s| s.trim())
+            .filter(|s| s.len() > 0)
+            .enumerate()
+        {
+            board[i][j] = c
+                .parse::<i64>()
+                .map_err(|e| Error::Parse(sections.diagnostic(section, i, c, e.to_string())))?;
+        }
+    }
+
+    Ok(Board(board))
+}
+
+fn parse_comma_separated_nums(sections: &Sections) -> Result<Vec<i64>, Error> {
+    let mut nums = Vec::new();
+    for (line, text) in sections[0].iter().enumerate() {
+        for s in text.split(",") {
+            nums.push(
+                s.parse::<i64>()
+                    .map_err(|e| Error::Parse(sections.diagnostic(0, line, s, e.to_string())))?,
+            );
+        }
+    }
+
+    Ok(nums)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day4;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day4::parse(Source::file(test_input)).unwrap();
+        let ret = Day4::part_one(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 4512);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day4::parse(Source::file(test_input)).unwrap();
+        let ret = Day4::part_two(&input, &());
+        assert_eq!(ret.is_ok(), true);
+        assert_eq!(ret.unwrap(), 1924);
+    }
+}
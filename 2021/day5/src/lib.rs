@@ -0,0 +1,108 @@
+use aoc_common::{input, Solution, Source};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    NotImplemented,
+}
+
+#[derive(Debug)]
+pub struct Vent {
+    x1: i64,
+    y1: i64,
+    x2: i64,
+    y2: i64,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<Vent>;
+    type Config = ();
+    type PartOne = usize;
+    type PartTwo = usize;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<Vent>, Error> {
+        // 234,455 -> 604,85
+        input::parse_regex_records(source, r"(\d+),(\d+) -> (\d+),(\d+)", |captures| {
+            Ok(Vent {
+                x1: input::capture_int(captures, 1)?,
+                y1: input::capture_int(captures, 2)?,
+                x2: input::capture_int(captures, 3)?,
+                y2: input::capture_int(captures, 4)?,
+            })
+        })
+        .map_err(Error::Input)
+    }
+
+    fn part_one(vents: &Vec<Vent>, _: &()) -> Result<usize, Error> {
+        Ok(count_overlaps(vents, false))
+    }
+
+    fn part_two(vents: &Vec<Vent>, _: &()) -> Result<usize, Error> {
+        Ok(count_overlaps(vents, true))
+    }
+}
+
+fn count_overlaps(vents: &[Vent], include_diagonals: bool) -> usize {
+    // create sparse 2D array of danger spots
+    let mut grid: HashMap<(i64, i64), usize> = HashMap::new();
+
+    for vent in vents {
+        if vent.x1 == vent.x2 {
+            // vertical vent
+            let y_start = std::cmp::min(vent.y1, vent.y2);
+            let y_end = std::cmp::max(vent.y1, vent.y2);
+            // +1 to include final cell
+            for y in y_start..y_end + 1 {
+                *grid.entry((vent.x1, y)).or_insert(0) += 1;
+            }
+        } else if vent.y1 == vent.y2 {
+            // vertical vent
+            let x_start = std::cmp::min(vent.x1, vent.x2);
+            let x_end = std::cmp::max(vent.x1, vent.x2);
+            // +1 to include final cell
+            for x in x_start..x_end + 1 {
+                *grid.entry((x, vent.y1)).or_insert(0) += 1;
+            }
+        } else if include_diagonals {
+            // diagonal vent
+            let length = std::cmp::max(vent.x1, vent.x2) - std::cmp::min(vent.x1, vent.x2);
+            let d_x = if vent.x2 > vent.x1 { 1 } else { -1 };
+            let d_y = if vent.y2 > vent.y1 { 1 } else { -1 };
+
+            // +1 to include final cell
+            for i in 0..length + 1 {
+                let x = vent.x1 + d_x * i;
+                let y = vent.y1 + d_y * i;
+                *grid.entry((x, y)).or_insert(0) += 1;
+            }
+        }
+    }
+
+    grid.iter().filter(|(_, &v)| v >= 2).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day5;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let test_input = "1_test.txt";
+        let input = Day5::parse(Source::file(test_input)).unwrap();
+        let ret = Day5::part_one(&input, &());
+        assert_eq!(ret.unwrap(), 5);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let test_input = "1_test.txt";
+        let input = Day5::parse(Source::file(test_input)).unwrap();
+        let ret = Day5::part_two(&input, &());
+        assert_eq!(ret.unwrap(), 12);
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_common::number::Count;
+use aoc_common::{input, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+    Count(aoc_common::Error),
+}
+
+pub struct Config {
+    pub part_one_days: usize,
+    pub part_two_days: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            part_one_days: 80,
+            part_two_days: 256,
+        }
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<usize>;
+    type Config = Config;
+    type PartOne = Count;
+    type PartTwo = Count;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<usize>, Error> {
+        input::parse_comma_separated_ints::<usize>(source).map_err(Error::Input)
+    }
+
+    fn part_one(fish: &Vec<usize>, config: &Config) -> Result<Count, Error> {
+        count_lanternfish(&fish, config.part_one_days)
+    }
+
+    fn part_two(fish: &Vec<usize>, config: &Config) -> Result<Count, Error> {
+        count_lanternfish(&fish, config.part_two_days)
+    }
+}
+
+fn count_lanternfish(input: &Vec<usize>, days: usize) -> Result<Count, Error> {
+    let mut populations = [0u64; 9];
+
+    for fish in input {
+        populations[*fish] += 1;
+    }
+    let mut populations = populations.map(Count::from);
+
+    for _ in 0..days {
+        // every timer counts down, and those at 0 reset to 6 and have
+        // children with timers of 8
+        populations.rotate_left(1);
+        populations[6] = populations[6]
+            .checked_add(&populations[8])
+            .map_err(Error::Count)?;
+    }
+
+    Count::sum(&populations).map_err(Error::Count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_lanternfish;
+    use aoc_common::number::Count;
+
+    #[test]
+    fn test_count_lanternfish() {
+        let input = vec![3, 4, 3, 1, 2];
+        assert_eq!(count_lanternfish(&input, 18).unwrap(), Count::from(26));
+        assert_eq!(count_lanternfish(&input, 80).unwrap(), Count::from(5934));
+        assert_eq!(
+            count_lanternfish(&input, 256).unwrap(),
+            Count::from(26984457539)
+        );
+    }
+
+    #[test]
+    fn test_many_days() {
+        let input = vec![3, 4, 3, 1, 2];
+        let count = |days| count_lanternfish(&input, days);
+
+        #[cfg(not(feature = "bigint"))]
+        assert!(matches!(
+            count(1000),
+            Err(super::Error::Count(aoc_common::Error::Arithmetic(_)))
+        ));
+
+        // each fish alive on day n is one alive on day n - 7 or born to one
+        // alive on day n - 9
+        #[cfg(feature = "bigint")]
+        assert_eq!(
+            count(1000).unwrap(),
+            count(993)
+                .unwrap()
+                .checked_add(&count(991).unwrap())
+                .unwrap()
+        );
+    }
+}
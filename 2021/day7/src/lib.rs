@@ -0,0 +1,107 @@
+use aoc_common::{input, Solution, Source};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<i64>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Vec<i64>, Error> {
+        input::parse_comma_separated_ints::<i64>(source).map_err(Error::Input)
+    }
+
+    fn part_one(input: &Vec<i64>, _: &()) -> Result<i64, Error> {
+        Ok(fuel_cost_linear(&input))
+    }
+
+    fn part_two(input: &Vec<i64>, _: &()) -> Result<i64, Error> {
+        Ok(fuel_cost_exponential(&input))
+    }
+}
+
+fn fuel_cost_linear(input: &Vec<i64>) -> i64 {
+    // map position to count
+    let mut crabs: HashMap<i64, i64> = HashMap::new();
+    for &i in input {
+        *crabs.entry(i).or_insert(0) += 1;
+    }
+
+    // calculates cost for a given position
+    let cost_for_position = |p: i64| {
+        crabs
+            .iter()
+            .fold(0, |s, (pos, count)| s + (p - pos).abs() * count)
+    };
+
+    let mut best_cost = cost_for_position(input[0]);
+    for &pos in crabs.keys() {
+        let cost = cost_for_position(pos);
+        if cost < best_cost {
+            best_cost = cost;
+        }
+    }
+    best_cost
+}
+
+fn fuel_cost_exponential(input: &Vec<i64>) -> i64 {
+    let max_pos = *input.iter().max().unwrap();
+    let min_pos = *input.iter().min().unwrap();
+
+    // map position to count
+    let mut crabs: HashMap<i64, i64> = HashMap::new();
+    for &i in input {
+        *crabs.entry(i).or_insert(0) += 1;
+    }
+
+    // 1 => 1
+    // 2 => 3
+    // 3 => 6
+    // 4 => 10
+    // 5 => 15
+    // F => (P+1)P/2
+
+    // calculates cost for a given position
+    let cost_for_position = |p: i64| {
+        crabs.iter().fold(0, |s, (pos, count)| {
+            let d = (p - pos).abs();
+            let f = (d * (d + 1)) / 2;
+            s + f * count
+        })
+    };
+
+    let mut best_cost = cost_for_position(input[0]);
+    for pos in min_pos..=max_pos {
+        let cost = cost_for_position(pos);
+        if cost < best_cost {
+            best_cost = cost;
+        }
+    }
+    best_cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuel_cost_exponential;
+    use super::fuel_cost_linear;
+
+    #[test]
+    fn test_fuel_cost_linear() {
+        let input = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!(fuel_cost_linear(&input), 37);
+    }
+
+    #[test]
+    fn test_fuel_cost_exponential() {
+        let input = vec![16, 1, 2, 0, 4, 2, 7, 1, 2, 14];
+        assert_eq!(fuel_cost_exponential(&input), 168);
+    }
+}
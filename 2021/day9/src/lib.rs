@@ -0,0 +1,71 @@
+use aoc_common::grid::Pos;
+use aoc_common::{graph, Grid, Solution, Source};
+
+#[derive(Debug)]
+pub enum Error {
+    Input(aoc_common::Error),
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Grid<i64>;
+    type Config = ();
+    type PartOne = i64;
+    type PartTwo = i64;
+    type Error = Error;
+
+    fn parse(source: Source) -> Result<Grid<i64>, Error> {
+        Grid::parse(source, |c| c.to_digit(10).map(i64::from)).map_err(Error::Input)
+    }
+
+    fn part_one(heightmap: &Grid<i64>, _: &()) -> Result<i64, Error> {
+        Ok(get_low_points(heightmap)
+            .iter()
+            .map(|&pos| heightmap[pos] + 1)
+            .sum())
+    }
+
+    fn part_two(heightmap: &Grid<i64>, _: &()) -> Result<i64, Error> {
+        // basins are the areas between cells of height 9
+        let floor = heightmap.positions().filter(|&pos| heightmap[pos] < 9);
+        let mut basin_sizes: Vec<i64> = graph::components(floor, |&pos| {
+            heightmap
+                .neighbours4(pos)
+                .filter(|&next| heightmap[next] < 9)
+                .collect::<Vec<Pos>>()
+        })
+        .iter()
+        .map(|basin| basin.len() as i64)
+        .collect();
+
+        basin_sizes.sort();
+        Ok(basin_sizes.iter().rev().take(3).product())
+    }
+}
+
+fn get_low_points(heightmap: &Grid<i64>) -> Vec<Pos> {
+    heightmap
+        .iter()
+        .filter(|&(pos, &val)| heightmap.neighbours4(pos).all(|n| heightmap[n] > val))
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Day9;
+    use aoc_common::{Solution, Source};
+
+    #[test]
+    fn test_part_one() {
+        let res = Day9::part_one(&Day9::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 15);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let res = Day9::part_two(&Day9::parse(Source::file("1_test.txt")).unwrap(), &());
+        assert_eq!(res.unwrap(), 1134);
+    }
+}
@@ -0,0 +1,572 @@
+use crate::Error;
+use std::collections::{HashMap, HashSet};
+
+// a cell in D-dimensional space
+pub type Point<const D: usize> = [i64; D];
+
+// which cells count as a cell's neighbours. Neighbourhoods must be symmetric
+// (if b is a neighbour of a then a is a neighbour of b), as neighbour counts
+// are gathered by visiting the neighbours of live cells.
+pub trait Neighbourhood<const D: usize> {
+    fn for_each(&self, p: &Point<D>, f: impl FnMut(Point<D>));
+
+    // how far along any axis a neighbour can be
+    fn reach(&self) -> i64;
+}
+
+// the same offsets from every cell
+#[derive(Debug, Clone)]
+pub struct Offsets<const D: usize>(Vec<Point<D>>);
+
+impl<const D: usize> Offsets<D> {
+    // every cell within one step along each axis, diagonals included
+    pub fn moore() -> Offsets<D> {
+        let count = 3usize.pow(D as u32);
+        Offsets(
+            (0..count)
+                .map(|n| {
+                    let mut offset = [0; D];
+                    let mut n = n;
+                    for axis in offset.iter_mut() {
+                        *axis = (n % 3) as i64 - 1;
+                        n /= 3;
+                    }
+                    offset
+                })
+                .filter(|offset| offset.iter().any(|&d| d != 0))
+                .collect(),
+        )
+    }
+
+    // the cells one step away along a single axis
+    pub fn von_neumann() -> Offsets<D> {
+        let mut offsets = Vec::with_capacity(2 * D);
+        for axis in 0..D {
+            for d in [-1, 1] {
+                let mut offset = [0; D];
+                offset[axis] = d;
+                offsets.push(offset);
+            }
+        }
+        Offsets(offsets)
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<const D: usize> Neighbourhood<D> for Offsets<D> {
+    fn for_each(&self, p: &Point<D>, mut f: impl FnMut(Point<D>)) {
+        for offset in &self.0 {
+            let mut q = *p;
+            for (axis, d) in q.iter_mut().zip(offset) {
+                *axis += d;
+            }
+            f(q);
+        }
+    }
+
+    fn reach(&self) -> i64 {
+        self.0.iter().flatten().map(|d| d.abs()).max().unwrap_or(0)
+    }
+}
+
+// neighbours listed cell by cell, for neighbourhoods that depend on the
+// layout such as the seats visible from each seat
+#[derive(Debug, Clone, Default)]
+pub struct Graph<const D: usize> {
+    neighbours: HashMap<Point<D>, Vec<Point<D>>>,
+    reach: i64,
+}
+
+impl<const D: usize> Graph<D> {
+    pub fn new(neighbours: HashMap<Point<D>, Vec<Point<D>>>) -> Graph<D> {
+        let reach = neighbours
+            .iter()
+            .flat_map(|(p, qs)| qs.iter().map(move |q| (p, q)))
+            .flat_map(|(p, q)| p.iter().zip(q).map(|(a, b)| (a - b).abs()))
+            .max()
+            .unwrap_or(0);
+
+        Graph { neighbours, reach }
+    }
+}
+
+impl<const D: usize> Neighbourhood<D> for Graph<D> {
+    fn for_each(&self, p: &Point<D>, f: impl FnMut(Point<D>)) {
+        if let Some(neighbours) = self.neighbours.get(p) {
+            neighbours.iter().copied().for_each(f);
+        }
+    }
+
+    fn reach(&self) -> i64 {
+        self.reach
+    }
+}
+
+// the live neighbour counts at which a dead cell comes alive and a live cell stays alive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    birth: Vec<bool>,
+    survival: Vec<bool>,
+}
+
+impl Rule {
+    pub fn new(birth: &[usize], survival: &[usize]) -> Rule {
+        let table = |counts: &[usize]| {
+            let mut table = vec![false; counts.iter().max().map_or(0, |&n| n + 1)];
+            for &n in counts {
+                table[n] = true;
+            }
+            table
+        };
+
+        Rule {
+            birth: table(birth),
+            survival: table(survival),
+        }
+    }
+
+    pub fn next(&self, alive: bool, neighbours: usize) -> bool {
+        let table = if alive { &self.survival } else { &self.birth };
+        table.get(neighbours).copied().unwrap_or(false)
+    }
+}
+
+// a set of live cells that can work out the next generation
+pub trait Cells<const D: usize>: Sized {
+    fn from_live(live: impl IntoIterator<Item = Point<D>>) -> Self;
+
+    fn contains(&self, p: &Point<D>) -> bool;
+
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn live(&self) -> Vec<Point<D>>;
+
+    // cells outside `domain`, if given, are always dead. Without one, only
+    // cells near live ones are considered, so a rule that brings cells to
+    // life with no live neighbours needs a domain.
+    fn next(
+        &self,
+        rule: &Rule,
+        neighbourhood: &impl Neighbourhood<D>,
+        domain: Option<&Self>,
+    ) -> Self;
+}
+
+// live cells in a hash set, for patterns that are small compared to the
+// space they spread over
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Sparse<const D: usize>(HashSet<Point<D>>);
+
+impl<const D: usize> Cells<D> for Sparse<D> {
+    fn from_live(live: impl IntoIterator<Item = Point<D>>) -> Sparse<D> {
+        Sparse(live.into_iter().collect())
+    }
+
+    fn contains(&self, p: &Point<D>) -> bool {
+        self.0.contains(p)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn live(&self) -> Vec<Point<D>> {
+        self.0.iter().copied().collect()
+    }
+
+    fn next(
+        &self,
+        rule: &Rule,
+        neighbourhood: &impl Neighbourhood<D>,
+        domain: Option<&Sparse<D>>,
+    ) -> Sparse<D> {
+        let mut counts: HashMap<Point<D>, usize> = HashMap::new();
+        for p in &self.0 {
+            neighbourhood.for_each(p, |q| *counts.entry(q).or_default() += 1);
+        }
+
+        // cells with no live neighbours are only visited if they could change
+        let mut candidates: Vec<Point<D>> = counts.keys().copied().collect();
+        candidates.extend(self.0.iter().filter(|p| !counts.contains_key(*p)));
+        if let Some(domain) = domain.filter(|_| rule.next(false, 0)) {
+            candidates.extend(domain.0.iter().filter(|p| !counts.contains_key(*p)));
+        }
+
+        Sparse(
+            candidates
+                .into_iter()
+                .filter(|p| domain.is_none_or(|d| d.contains(p)))
+                .filter(|p| rule.next(self.contains(p), counts.get(p).copied().unwrap_or(0)))
+                .collect(),
+        )
+    }
+}
+
+// live cells as bits over the box that bounds them, for crowded patterns
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dense<const D: usize> {
+    bounds: Bounds<D>,
+    bits: Vec<u64>,
+    len: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Bounds<const D: usize> {
+    min: Point<D>,
+    size: [usize; D],
+}
+
+impl<const D: usize> Default for Bounds<D> {
+    fn default() -> Bounds<D> {
+        Bounds {
+            min: [0; D],
+            size: [0; D],
+        }
+    }
+}
+
+impl<const D: usize> Bounds<D> {
+    fn around<'a>(points: impl IntoIterator<Item = &'a Point<D>>) -> Bounds<D> {
+        let mut points = points.into_iter();
+        let Some(first) = points.next() else {
+            return Bounds::default();
+        };
+
+        let (mut min, mut max) = (*first, *first);
+        for p in points {
+            for axis in 0..D {
+                min[axis] = min[axis].min(p[axis]);
+                max[axis] = max[axis].max(p[axis]);
+            }
+        }
+
+        let mut size = [0; D];
+        for axis in 0..D {
+            size[axis] = (max[axis] - min[axis] + 1) as usize;
+        }
+        Bounds { min, size }
+    }
+
+    fn grow(&self, by: i64) -> Bounds<D> {
+        if self.volume() == 0 {
+            return *self;
+        }
+
+        let mut grown = *self;
+        for axis in 0..D {
+            grown.min[axis] -= by;
+            grown.size[axis] += 2 * by as usize;
+        }
+        grown
+    }
+
+    fn volume(&self) -> usize {
+        self.size.iter().product()
+    }
+
+    fn index(&self, p: &Point<D>) -> Option<usize> {
+        let mut idx = 0;
+        for axis in (0..D).rev() {
+            let offset = usize::try_from(p[axis] - self.min[axis]).ok()?;
+            if offset >= self.size[axis] {
+                return None;
+            }
+            idx = idx * self.size[axis] + offset;
+        }
+        Some(idx)
+    }
+
+    fn point(&self, mut idx: usize) -> Point<D> {
+        let mut p = self.min;
+        for (axis, size) in p.iter_mut().zip(self.size) {
+            *axis += (idx % size) as i64;
+            idx /= size;
+        }
+        p
+    }
+}
+
+impl<const D: usize> Cells<D> for Dense<D> {
+    fn from_live(live: impl IntoIterator<Item = Point<D>>) -> Dense<D> {
+        let live: Vec<Point<D>> = live.into_iter().collect();
+        let bounds = Bounds::around(&live);
+
+        let mut dense = Dense {
+            bounds,
+            bits: vec![0; bounds.volume().div_ceil(64)],
+            len: 0,
+        };
+        for p in &live {
+            if let Some(idx) = bounds.index(p) {
+                if dense.bits[idx / 64] & (1 << (idx % 64)) == 0 {
+                    dense.bits[idx / 64] |= 1 << (idx % 64);
+                    dense.len += 1;
+                }
+            }
+        }
+        dense
+    }
+
+    fn contains(&self, p: &Point<D>) -> bool {
+        self.bounds
+            .index(p)
+            .is_some_and(|idx| self.bits[idx / 64] & (1 << (idx % 64)) != 0)
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn live(&self) -> Vec<Point<D>> {
+        (0..self.bounds.volume())
+            .filter(|idx| self.bits[idx / 64] & (1 << (idx % 64)) != 0)
+            .map(|idx| self.bounds.point(idx))
+            .collect()
+    }
+
+    fn next(
+        &self,
+        rule: &Rule,
+        neighbourhood: &impl Neighbourhood<D>,
+        domain: Option<&Dense<D>>,
+    ) -> Dense<D> {
+        let bounds = match domain {
+            Some(domain) => domain.bounds,
+            None => self.bounds.grow(neighbourhood.reach()),
+        };
+
+        let live = self.live();
+        let mut counts = vec![0u16; bounds.volume()];
+        for p in &live {
+            neighbourhood.for_each(p, |q| {
+                if let Some(idx) = bounds.index(&q) {
+                    counts[idx] += 1;
+                }
+            });
+        }
+
+        Dense::from_live(
+            counts
+                .iter()
+                .enumerate()
+                .map(|(idx, &n)| (bounds.point(idx), n as usize))
+                .filter(|(p, n)| {
+                    domain.is_none_or(|d| d.contains(p)) && rule.next(self.contains(p), *n)
+                })
+                .map(|(p, _)| p),
+        )
+    }
+}
+
+// a life-like cellular automaton: every generation each cell lives or dies
+// by the rule according to how many of its neighbours are alive
+#[derive(Debug, Clone)]
+pub struct Automaton<const D: usize, C, N> {
+    cells: C,
+    domain: Option<C>,
+    rule: Rule,
+    neighbourhood: N,
+}
+
+impl<const D: usize, C: Cells<D>, N: Neighbourhood<D>> Automaton<D, C, N> {
+    // fails if the rule brings cells to life with no live neighbours, as
+    // they would fill all of space. Use `within` for those.
+    pub fn new(
+        live: impl IntoIterator<Item = Point<D>>,
+        rule: Rule,
+        neighbourhood: N,
+    ) -> Result<Automaton<D, C, N>, Error> {
+        if rule.next(false, 0) {
+            return Err(Error::UnboundedBirths);
+        }
+
+        Ok(Automaton {
+            cells: C::from_live(live),
+            domain: None,
+            rule,
+            neighbourhood,
+        })
+    }
+
+    // an automaton confined to the given cells, e.g. the seats in a layout
+    pub fn within(
+        live: impl IntoIterator<Item = Point<D>>,
+        rule: Rule,
+        neighbourhood: N,
+        domain: impl IntoIterator<Item = Point<D>>,
+    ) -> Automaton<D, C, N> {
+        Automaton {
+            cells: C::from_live(live),
+            domain: Some(C::from_live(domain)),
+            rule,
+            neighbourhood,
+        }
+    }
+
+    pub fn cells(&self) -> &C {
+        &self.cells
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn step(&mut self) {
+        self.cells = self
+            .cells
+            .next(&self.rule, &self.neighbourhood, self.domain.as_ref());
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        self.run_with(generations, |_| {});
+    }
+
+    // as `run`, calling `each` with the starting cells and every generation after
+    pub fn run_with(&mut self, generations: usize, mut each: impl FnMut(&C)) {
+        each(&self.cells);
+        for _ in 0..generations {
+            self.step();
+            each(&self.cells);
+        }
+    }
+
+    // steps until a generation is the same as the one before, returning the
+    // number of steps that changed something
+    pub fn settle(&mut self) -> usize
+    where
+        C: PartialEq,
+    {
+        self.settle_with(|_| {})
+    }
+
+    // as `settle`, calling `each` with the starting cells and every generation
+    // that changed something
+    pub fn settle_with(&mut self, mut each: impl FnMut(&C)) -> usize
+    where
+        C: PartialEq,
+    {
+        each(&self.cells);
+        let mut steps = 0;
+        loop {
+            let next = self
+                .cells
+                .next(&self.rule, &self.neighbourhood, self.domain.as_ref());
+            if next == self.cells {
+                return steps;
+            }
+            self.cells = next;
+            each(&self.cells);
+            steps += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Automaton, Cells, Dense, Graph, Neighbourhood, Offsets, Point, Rule, Sparse};
+    use crate::Error;
+    use std::collections::HashMap;
+
+    fn glider() -> Vec<Point<2>> {
+        vec![[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]]
+    }
+
+    fn sorted<const D: usize>(cells: &impl Cells<D>) -> Vec<Point<D>> {
+        let mut live = cells.live();
+        live.sort();
+        live
+    }
+
+    #[test]
+    fn test_offsets() {
+        assert_eq!(Offsets::<2>::moore().len(), 8);
+        assert_eq!(Offsets::<4>::moore().len(), 80);
+        assert_eq!(Offsets::<3>::von_neumann().len(), 6);
+
+        let mut neighbours = Vec::new();
+        Offsets::<1>::moore().for_each(&[5], |q| neighbours.push(q));
+        assert_eq!(neighbours, vec![[4], [6]]);
+    }
+
+    #[test]
+    fn test_glider() {
+        // a glider moves one cell diagonally every four generations
+        let conway = Rule::new(&[3], &[2, 3]);
+        let mut sparse: Automaton<2, Sparse<2>, _> =
+            Automaton::new(glider(), conway.clone(), Offsets::moore()).unwrap();
+        let mut dense: Automaton<2, Dense<2>, _> =
+            Automaton::new(glider(), conway, Offsets::moore()).unwrap();
+
+        sparse.run(4);
+        dense.run(4);
+
+        let moved: Vec<Point<2>> = glider().iter().map(|&[x, y]| [x + 1, y + 1]).collect();
+        let mut expected = moved.clone();
+        expected.sort();
+        assert_eq!(sorted(sparse.cells()), expected);
+        assert_eq!(sorted(dense.cells()), expected);
+        assert_eq!(dense.cells(), &Dense::from_live(moved));
+    }
+
+    #[test]
+    fn test_domain_and_graph() {
+        // three cells in a row where each only sees the next one along, and
+        // an empty cell fills when none of its neighbours are full
+        let line: Vec<Point<1>> = vec![[0], [1], [2]];
+        let graph = Graph::new(HashMap::from([
+            ([0], vec![[1]]),
+            ([1], vec![[0], [2]]),
+            ([2], vec![[1]]),
+        ]));
+        assert_eq!(graph.reach(), 1);
+
+        let rule = Rule::new(&[0], &[0, 1]);
+        let unbounded: Result<Automaton<1, Sparse<1>, _>, _> =
+            Automaton::new(Vec::new(), rule.clone(), graph.clone());
+        assert!(matches!(unbounded, Err(Error::UnboundedBirths)));
+        let mut seats: Automaton<1, Dense<1>, _> = Automaton::within(Vec::new(), rule, graph, line);
+
+        seats.step();
+        assert_eq!(seats.len(), 3);
+        seats.step();
+        assert_eq!(sorted(seats.cells()), vec![[0], [2]]);
+        assert_eq!(seats.settle(), 0);
+    }
+
+    #[test]
+    fn test_recording() {
+        // a blinker flips back and forth, a block never changes
+        let conway = Rule::new(&[3], &[2, 3]);
+        let blinker = vec![[0, 1], [1, 1], [2, 1]];
+        let mut automaton: Automaton<2, Sparse<2>, _> =
+            Automaton::new(blinker.clone(), conway.clone(), Offsets::moore()).unwrap();
+        let mut frames = Vec::new();
+        automaton.run_with(2, |cells| frames.push(sorted(cells)));
+        assert_eq!(
+            frames,
+            vec![blinker.clone(), vec![[1, 0], [1, 1], [1, 2]], blinker]
+        );
+
+        let block = vec![[0, 0], [0, 1], [1, 0], [1, 1]];
+        let mut automaton: Automaton<2, Sparse<2>, _> =
+            Automaton::new(block.clone(), conway, Offsets::moore()).unwrap();
+        let mut frames = Vec::new();
+        assert_eq!(automaton.settle_with(|cells| frames.push(sorted(cells))), 0);
+        assert_eq!(frames, vec![block]);
+    }
+}
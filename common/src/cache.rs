@@ -0,0 +1,195 @@
+use crate::Error;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// supplies the puzzle input for a day that isn't cached yet
+pub trait Fetch: Send + Sync {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, Error>;
+}
+
+// downloads `<base_url>/<year>/day/<day>/input` with a session cookie
+pub struct Http {
+    base_url: String,
+    session: String,
+}
+
+impl Http {
+    pub fn new(base_url: &str, session: &str) -> Http {
+        Http {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+        }
+    }
+
+    // AOC_SESSION holds the session cookie, AOC_BASE_URL optionally replaces the site
+    pub fn from_env() -> Option<Http> {
+        let session = env::var("AOC_SESSION").ok()?;
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+
+        Some(Http::new(&base_url, &session))
+    }
+}
+
+impl Fetch for Http {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, Error> {
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+
+        ureq::get(&url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", "github.com/peterellisjones/advent-of-code")
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| Error::Fetch(format!("{}: {}", url, e)))
+    }
+}
+
+// puzzle inputs stored as `<dir>/<year>/day<DD>.txt`, fetched on first use
+pub struct InputCache {
+    dir: PathBuf,
+    fetch: Option<Box<dyn Fetch>>,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache {
+            dir: dir.into(),
+            fetch: None,
+        }
+    }
+
+    pub fn with_fetch(mut self, fetch: impl Fetch + 'static) -> InputCache {
+        self.fetch = Some(Box::new(fetch));
+        self
+    }
+
+    // the repository's inputs directory unless AOC_INPUT_DIR is set, fetching
+    // over HTTP when AOC_SESSION is set
+    pub fn from_env() -> InputCache {
+        let dir = match env::var_os("AOC_INPUT_DIR") {
+            Some(dir) => PathBuf::from(dir),
+            None => match Path::new(env!("CARGO_MANIFEST_DIR")).parent() {
+                Some(root) => root.join("inputs"),
+                None => PathBuf::from("inputs"),
+            },
+        };
+
+        let cache = InputCache::new(dir);
+        match Http::from_env() {
+            Some(http) => cache.with_fetch(http),
+            None => cache,
+        }
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    pub fn get(&self, year: u32, day: u32) -> Result<PathBuf, Error> {
+        let path = self.path(year, day);
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let fetch = self
+            .fetch
+            .as_ref()
+            .ok_or_else(|| Error::MissingInput(path.clone()))?;
+        let contents = fetch.fetch(year, day)?;
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(Error::IO)?;
+        }
+        fs::write(&path, contents).map_err(Error::IO)?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Http, InputCache};
+    use crate::Error;
+    use std::fs;
+    use std::path::PathBuf;
+    use std::thread;
+    use tiny_http::{Response, Server};
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}-{}", std::process::id(), name));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    // serves a single request and returns its url and cookie
+    fn serve_once(
+        status: u16,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<(String, String)>) {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", server.server_addr().to_ip().unwrap());
+
+        let handle = thread::spawn(move || {
+            let request = server.recv().unwrap();
+            let url = request.url().to_string();
+            let cookie = request
+                .headers()
+                .iter()
+                .find(|h| h.field.equiv("Cookie"))
+                .map(|h| h.value.to_string())
+                .unwrap_or_default();
+            request
+                .respond(Response::from_string(body).with_status_code(status))
+                .unwrap();
+            (url, cookie)
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_and_cache() {
+        let dir = scratch_dir("fetch");
+        let (base_url, server) = serve_once(200, "1\n2\n3\n");
+        let cache = InputCache::new(&dir).with_fetch(Http::new(&base_url, "abc123"));
+
+        let path = cache.get(2020, 1).unwrap();
+        assert_eq!(path, dir.join("2020").join("day01.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1\n2\n3\n");
+
+        let (url, cookie) = server.join().unwrap();
+        assert_eq!(url, "/2020/day/1/input");
+        assert_eq!(cookie, "session=abc123");
+
+        // the server only answers once, so this must come from the cache
+        assert_eq!(cache.get(2020, 1).unwrap(), path);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_error() {
+        let dir = scratch_dir("error");
+        let (base_url, server) = serve_once(400, "Please log in");
+        let cache = InputCache::new(&dir).with_fetch(Http::new(&base_url, "expired"));
+
+        assert!(matches!(cache.get(2021, 3), Err(Error::Fetch(_))));
+        assert!(!cache.path(2021, 3).exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let dir = scratch_dir("missing");
+        let cache = InputCache::new(&dir);
+
+        match cache.get(2019, 4) {
+            Err(Error::MissingInput(path)) => assert_eq!(path, dir.join("2019").join("day04.txt")),
+            other => panic!("expected a missing input, got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+
+// points at the offending part of a puzzle input. Renders as
+//
+//   error: invalid digit found in string
+//    --> 2021/day4/1.txt:3:7
+//     |
+//   3 | 22 13 x7 11  0
+//     |       ^^
+#[derive(Clone, PartialEq)]
+pub struct Diagnostic {
+    pub path: String,
+    // 1-based
+    pub line: usize,
+    // 1-based, in characters
+    pub column: usize,
+    pub snippet: String,
+    // number of characters underlined, at least one
+    pub width: usize,
+    pub message: String,
+}
+
+impl Diagnostic {
+    // `token` should be a slice of `text`. Other strings are searched for in
+    // `text`, and the whole line is underlined when they can't be found.
+    pub fn new(
+        path: &str,
+        line: usize,
+        text: &str,
+        token: &str,
+        message: impl Into<String>,
+    ) -> Diagnostic {
+        let start = text.as_ptr() as usize;
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(start)
+            .filter(|&o| o + token.len() <= text.len() && text.is_char_boundary(o))
+            .or_else(|| text.find(token).filter(|_| !token.is_empty()));
+
+        match offset {
+            Some(offset) => Diagnostic::at(
+                path,
+                line,
+                text,
+                text[..offset].chars().count() + 1,
+                token.chars().count(),
+                message,
+            ),
+            None => Diagnostic::at(path, line, text, 1, text.chars().count(), message),
+        }
+    }
+
+    pub fn at(
+        path: &str,
+        line: usize,
+        text: &str,
+        column: usize,
+        width: usize,
+        message: impl Into<String>,
+    ) -> Diagnostic {
+        Diagnostic {
+            path: path.to_string(),
+            line,
+            column: column.max(1),
+            snippet: text.to_string(),
+            width: width.max(1),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        writeln!(f, "error: {}", self.message)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter, self.path, self.line, self.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.snippet)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.column - 1),
+            "^".repeat(self.width)
+        )
+    }
+}
+
+// days print errors with {:?}, so show the rendered form there too
+impl fmt::Debug for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Diagnostic;
+
+    #[test]
+    fn test_render() {
+        let line = "22 13 x7 11  0";
+        let diagnostic = Diagnostic::new(
+            "2021/day4/1.txt",
+            3,
+            line,
+            &line[6..8],
+            "invalid digit found in string",
+        );
+
+        assert_eq!((diagnostic.column, diagnostic.width), (7, 2));
+        assert_eq!(
+            diagnostic.to_string(),
+            "error: invalid digit found in string\n \
+             --> 2021/day4/1.txt:3:7\n  \
+             |\n\
+             3 | 22 13 x7 11  0\n  \
+             |       ^^"
+        );
+    }
+
+    #[test]
+    fn test_token_lookup() {
+        let diagnostic = Diagnostic::new("1.txt", 12, "fold along z=7", "z=7", "unknown axis");
+        assert_eq!((diagnostic.column, diagnostic.width), (12, 3));
+        assert!(diagnostic.to_string().ends_with("\n   |            ^^^"));
+
+        let missing = Diagnostic::new("1.txt", 1, "abc", "xyz", "bad line");
+        assert_eq!((missing.column, missing.width), (1, 3));
+
+        let empty = Diagnostic::new("1.txt", 1, "", "", "empty line");
+        assert_eq!((empty.column, empty.width), (1, 1));
+    }
+}
@@ -0,0 +1,59 @@
+use crate::Diagnostic;
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    ParseInt(std::num::ParseIntError),
+    Regex(regex::Error),
+    NoCapture(String),
+    Parse(Diagnostic),
+    SectionCount { expected: usize, found: usize },
+    MissingInput(PathBuf),
+    Fetch(String),
+    GlyphHeight(usize),
+    UnknownGlyph(String),
+    Modulus(i64),
+    Inconsistent { residue: i64, modulus: i64 },
+    Arithmetic(String),
+    // an automaton whose rule brings cells to life with no live neighbours,
+    // and no domain to stop them filling all of space
+    UnboundedBirths,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{}", e),
+            Error::ParseInt(e) => write!(f, "{}", e),
+            Error::Regex(e) => write!(f, "{}", e),
+            Error::NoCapture(s) => write!(f, "no capture in {:?}", s),
+            Error::Parse(d) => write!(f, "{}", d),
+            Error::SectionCount { expected, found } => {
+                write!(f, "expected {} sections, found {}", expected, found)
+            }
+            Error::MissingInput(path) => write!(
+                f,
+                "no input at {}, set AOC_SESSION to download it",
+                path.display()
+            ),
+            Error::Fetch(s) => write!(f, "cannot fetch {}", s),
+            Error::GlyphHeight(height) => {
+                write!(f, "letters are 6 or 10 dots tall, found {}", height)
+            }
+            Error::UnknownGlyph(bitmap) => write!(f, "unrecognised letter\n{}", bitmap),
+            Error::Modulus(m) => write!(f, "moduli must be positive, found {}", m),
+            Error::Inconsistent { residue, modulus } => write!(
+                f,
+                "x = {} (mod {}) contradicts the congruences before it",
+                residue, modulus
+            ),
+            Error::Arithmetic(expr) => write!(f, "{} overflows or is undefined", expr),
+            Error::UnboundedBirths => write!(
+                f,
+                "cells born with no live neighbours need a domain to live in"
+            ),
+        }
+    }
+}
@@ -0,0 +1,330 @@
+use crate::{Diagnostic, Error};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+// a binary operator. `apply` returns None when the result overflows or is
+// undefined, e.g. division by zero
+#[derive(Debug, Clone, Copy)]
+pub struct Operator {
+    pub symbol: char,
+    pub precedence: u32,
+    pub associativity: Associativity,
+    pub apply: fn(i64, i64) -> Option<i64>,
+}
+
+// the operators an expression may use and how tightly each binds. Higher
+// precedences bind tighter.
+#[derive(Debug, Clone, Default)]
+pub struct Operators {
+    binary: Vec<Operator>,
+    // unary minus, if allowed
+    negation: Option<u32>,
+}
+
+impl Operators {
+    pub fn new() -> Operators {
+        Operators::default()
+    }
+
+    // + - * / and ^ with their usual precedence, and a unary minus that binds
+    // looser than ^ so that -2^2 is -4
+    pub fn arithmetic() -> Operators {
+        use Associativity::{Left, Right};
+        Operators::new()
+            .binary('+', 1, Left, i64::checked_add)
+            .binary('-', 1, Left, i64::checked_sub)
+            .binary('*', 2, Left, i64::checked_mul)
+            .binary('/', 2, Left, i64::checked_div)
+            .negation(3)
+            .binary('^', 4, Right, |base, exp| {
+                base.checked_pow(u32::try_from(exp).ok()?)
+            })
+    }
+
+    // a later operator with the same symbol replaces the earlier one
+    pub fn binary(
+        mut self,
+        symbol: char,
+        precedence: u32,
+        associativity: Associativity,
+        apply: fn(i64, i64) -> Option<i64>,
+    ) -> Operators {
+        self.binary.retain(|op| op.symbol != symbol);
+        self.binary.push(Operator {
+            symbol,
+            precedence,
+            associativity,
+            apply,
+        });
+        self
+    }
+
+    // allow a leading - to negate whatever binds tighter than `precedence`
+    pub fn negation(mut self, precedence: u32) -> Operators {
+        self.negation = Some(precedence);
+        self
+    }
+
+    fn get(&self, symbol: char) -> Option<Operator> {
+        self.binary.iter().find(|op| op.symbol == symbol).copied()
+    }
+
+    // errors point at line 1 of an unnamed input, callers with a real
+    // location should fill it in
+    pub fn parse(&self, text: &str) -> Result<Expr, Error> {
+        let mut parser = Parser {
+            operators: self,
+            text,
+            tokens: self.tokenize(text)?,
+            next: 0,
+        };
+        let expr = parser.expression(0)?;
+
+        match parser.tokens.get(parser.next) {
+            None => Ok(expr),
+            Some(&(Token::Close, span)) => Err(parser.error(span, "unmatched )")),
+            Some(&(_, span)) => Err(parser.error(span, "expected an operator")),
+        }
+    }
+
+    fn tokenize(&self, text: &str) -> Result<Vec<(Token, Span)>, Error> {
+        let error = |span: Span, message: String| {
+            Error::Parse(Diagnostic::at("<input>", 1, text, span.0, span.1, message))
+        };
+
+        let mut tokens = Vec::new();
+        let mut chars = text.chars().enumerate().peekable();
+        while let Some((idx, c)) = chars.next() {
+            let col = idx + 1;
+            let token = match c {
+                _ if c.is_whitespace() => continue,
+                '(' => Token::Open,
+                ')' => Token::Close,
+                '0'..='9' => {
+                    let mut digits = c.to_string();
+                    while let Some((_, d)) = chars.next_if(|(_, d)| d.is_ascii_digit()) {
+                        digits.push(d);
+                    }
+                    let span = (col, digits.len());
+                    let n = digits
+                        .parse()
+                        .map_err(|_| error(span, format!("{} is too large", digits)))?;
+                    tokens.push((Token::Num(n), span));
+                    continue;
+                }
+                '-' if self.negation.is_some() => Token::Symbol(c),
+                _ if self.get(c).is_some() => Token::Symbol(c),
+                _ => return Err(error((col, 1), format!("unexpected character {:?}", c))),
+            };
+            tokens.push((token, (col, 1)));
+        }
+
+        Ok(tokens)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Num(i64),
+    Neg(Box<Expr>),
+    Binary {
+        operator: Operator,
+        left: Box<Expr>,
+        right: Box<Expr>,
+    },
+}
+
+impl Expr {
+    pub fn eval(&self) -> Result<i64, Error> {
+        let value = match self {
+            Expr::Num(n) => Some(*n),
+            Expr::Neg(e) => e.eval()?.checked_neg(),
+            Expr::Binary {
+                operator,
+                left,
+                right,
+            } => (operator.apply)(left.eval()?, right.eval()?),
+        };
+
+        value.ok_or_else(|| Error::Arithmetic(self.to_string()))
+    }
+}
+
+// every operation in parentheses, showing how precedence grouped them
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Num(n) => write!(f, "{}", n),
+            Expr::Neg(e) => write!(f, "(-{})", e),
+            Expr::Binary {
+                operator,
+                left,
+                right,
+            } => write!(f, "({} {} {})", left, operator.symbol, right),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Token {
+    Num(i64),
+    Symbol(char),
+    Open,
+    Close,
+}
+
+// 1-based column and width in characters
+type Span = (usize, usize);
+
+struct Parser<'a> {
+    operators: &'a Operators,
+    text: &'a str,
+    tokens: Vec<(Token, Span)>,
+    next: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, span: Span, message: &str) -> Error {
+        Error::Parse(Diagnostic::at(
+            "<input>", 1, self.text, span.0, span.1, message,
+        ))
+    }
+
+    fn end(&self) -> Span {
+        (self.text.chars().count() + 1, 1)
+    }
+
+    // precedence climbing: gather operators binding at least as tightly as
+    // `min`, leaving looser ones to the caller
+    fn expression(&mut self, min: u32) -> Result<Expr, Error> {
+        let mut left = self.operand()?;
+
+        while let Some(&(Token::Symbol(c), _)) = self.tokens.get(self.next) {
+            let Some(operator) = self.operators.get(c).filter(|op| op.precedence >= min) else {
+                break;
+            };
+            self.next += 1;
+
+            let right = self.expression(match operator.associativity {
+                Associativity::Left => operator.precedence + 1,
+                Associativity::Right => operator.precedence,
+            })?;
+            left = Expr::Binary {
+                operator,
+                left: Box::new(left),
+                right: Box::new(right),
+            };
+        }
+
+        Ok(left)
+    }
+
+    fn operand(&mut self) -> Result<Expr, Error> {
+        let Some(&(token, span)) = self.tokens.get(self.next) else {
+            return Err(self.error(self.end(), "expected a number or ("));
+        };
+        self.next += 1;
+
+        match (token, self.operators.negation) {
+            (Token::Num(n), _) => Ok(Expr::Num(n)),
+            (Token::Symbol('-'), Some(precedence)) => {
+                let inner = self.expression(precedence)?;
+                Ok(Expr::Neg(Box::new(inner)))
+            }
+            (Token::Open, _) => {
+                let inner = self.expression(0)?;
+                match self.tokens.get(self.next) {
+                    Some((Token::Close, _)) => {
+                        self.next += 1;
+                        Ok(inner)
+                    }
+                    Some(&(_, span)) => Err(self.error(span, "expected an operator or )")),
+                    None => Err(self.error(span, "unclosed (")),
+                }
+            }
+            (Token::Symbol(_) | Token::Close, _) => Err(self.error(span, "expected a number or (")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Associativity, Operators};
+    use crate::Error;
+
+    fn show(operators: &Operators, text: &str) -> (String, i64) {
+        let expr = operators.parse(text).unwrap();
+        (expr.to_string(), expr.eval().unwrap())
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let ops = Operators::arithmetic();
+        assert_eq!(show(&ops, "1 + 2 * 3"), ("(1 + (2 * 3))".to_string(), 7));
+        assert_eq!(show(&ops, "10 - 4 - 3"), ("((10 - 4) - 3)".to_string(), 3));
+        assert_eq!(
+            show(&ops, "100 / 7 / 2"),
+            ("((100 / 7) / 2)".to_string(), 7)
+        );
+        assert_eq!(show(&ops, "2 ^ 3 ^ 2"), ("(2 ^ (3 ^ 2))".to_string(), 512));
+        assert_eq!(show(&ops, "-2 ^ 2"), ("(-(2 ^ 2))".to_string(), -4));
+        assert_eq!(show(&ops, "(-2) ^ 2"), ("((-2) ^ 2)".to_string(), 4));
+        assert_eq!(
+            show(&ops, "3 * -4 + 5"),
+            ("((3 * (-4)) + 5)".to_string(), -7)
+        );
+        assert_eq!(show(&ops, "--7"), ("(-(-7))".to_string(), 7));
+        assert_eq!(show(&ops, "(12)"), ("12".to_string(), 12));
+    }
+
+    #[test]
+    fn test_custom_table() {
+        // 2020 day 18: addition before multiplication
+        let ops = Operators::new()
+            .binary('+', 2, Associativity::Left, i64::checked_add)
+            .binary('*', 1, Associativity::Left, i64::checked_mul);
+        assert_eq!(
+            show(&ops, "2 * 3 + (4 * 5)"),
+            ("(2 * (3 + (4 * 5)))".to_string(), 46)
+        );
+
+        // subtraction that groups to the right
+        let ops = Operators::new().binary('-', 1, Associativity::Right, i64::checked_sub);
+        assert_eq!(show(&ops, "10 - 4 - 3"), ("(10 - (4 - 3))".to_string(), 9));
+        assert!(ops.parse("-1").is_err());
+    }
+
+    #[test]
+    fn test_errors() {
+        let ops = Operators::arithmetic();
+        let column = |text: &str| match ops.parse(text) {
+            Err(Error::Parse(d)) => (d.column, d.message),
+            other => panic!("unexpected {:?}", other),
+        };
+        assert_eq!(column("1 + x"), (5, "unexpected character 'x'".to_string()));
+        assert_eq!(column("1 +"), (4, "expected a number or (".to_string()));
+        assert_eq!(column("(1 + 2"), (1, "unclosed (".to_string()));
+        assert_eq!(column("1 + 2)"), (6, "unmatched )".to_string()));
+        assert_eq!(column("3 4"), (3, "expected an operator".to_string()));
+        assert_eq!(
+            column("(3 4)"),
+            (4, "expected an operator or )".to_string())
+        );
+        assert_eq!(
+            column("99999999999999999999"),
+            (1, "99999999999999999999 is too large".to_string())
+        );
+
+        for text in ["1 / 0", "2 ^ 63", "2 ^ -1", "-(-9223372036854775807 - 1)"] {
+            match ops.parse(text).unwrap().eval() {
+                Err(Error::Arithmetic(_)) => {}
+                other => panic!("{}: unexpected {:?}", text, other),
+            }
+        }
+    }
+}
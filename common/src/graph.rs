@@ -0,0 +1,380 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+// Graphs are given by functions from a node to its neighbours (or successors,
+// for directed graphs), so the same searches work on adjacency maps and on
+// graphs that are never built, such as positions in a grid or puzzle states.
+
+// the neighbour function of an adjacency map, where nodes missing from the map
+// have no neighbours
+pub fn adjacency<'a, N, C>(map: &'a HashMap<N, C>) -> impl Fn(&N) -> Vec<N> + 'a
+where
+    N: Eq + Hash + Clone + 'a,
+    &'a C: IntoIterator<Item = &'a N>,
+{
+    move |node| map.get(node).into_iter().flatten().cloned().collect()
+}
+
+// the number of steps to every node reachable from any of `starts`
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances: HashMap<N, usize> = HashMap::new();
+    let mut queue: VecDeque<N> = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in neighbours(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+// the cheapest path from `start` to a node where `goal` holds, with its cost,
+// where `successors` gives each next node with the cost of the step to it
+pub fn dijkstra<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, successors, |_| C::default(), goal)
+}
+
+// as `dijkstra`, searching towards the goal first. The heuristic must never
+// be more than the real cost from a node to the goal.
+pub fn astar<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Copy + Ord + Default + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // nodes are numbered as they are found, with the cheapest known cost to
+    // each and the node it was reached from
+    let mut nodes: Vec<N> = vec![start.clone()];
+    let mut numbers: HashMap<N, usize> = HashMap::from([(start, 0)]);
+    let mut best: Vec<(C, usize)> = vec![(C::default(), 0)];
+
+    let mut queue = BinaryHeap::new();
+    queue.push(Reverse((heuristic(&nodes[0]), C::default(), 0)));
+
+    while let Some(Reverse((_, cost, idx))) = queue.pop() {
+        // a cheaper way here was found after this entry was queued
+        if cost > best[idx].0 {
+            continue;
+        }
+
+        if goal(&nodes[idx]) {
+            let mut path = vec![nodes[idx].clone()];
+            let mut at = idx;
+            while at != 0 {
+                at = best[at].1;
+                path.push(nodes[at].clone());
+            }
+            path.reverse();
+            return Some((cost, path));
+        }
+
+        for (next, step) in successors(&nodes[idx]) {
+            let next_cost = cost + step;
+            let next_idx = match numbers.get(&next) {
+                Some(&next_idx) if next_cost >= best[next_idx].0 => continue,
+                Some(&next_idx) => {
+                    best[next_idx] = (next_cost, idx);
+                    next_idx
+                }
+                None => {
+                    nodes.push(next.clone());
+                    numbers.insert(next, nodes.len() - 1);
+                    best.push((next_cost, idx));
+                    nodes.len() - 1
+                }
+            };
+            queue.push(Reverse((
+                next_cost + heuristic(&nodes[next_idx]),
+                next_cost,
+                next_idx,
+            )));
+        }
+    }
+
+    None
+}
+
+// the connected components of an undirected graph containing `nodes`
+pub fn components<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> Vec<HashSet<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen: HashSet<N> = HashSet::new();
+    let mut components = Vec::new();
+
+    for node in nodes {
+        if seen.contains(&node) {
+            continue;
+        }
+
+        let component: HashSet<N> = bfs([node], &mut neighbours).into_keys().collect();
+        seen.extend(component.iter().cloned());
+        components.push(component);
+    }
+
+    components
+}
+
+// every node reachable from `nodes`, ordered so each comes before its
+// successors, or a cycle if there is no such order
+pub fn toposort<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Result<Vec<N>, Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut found: Vec<N> = Vec::new();
+    let mut edges: HashMap<N, Vec<N>> = HashMap::new();
+    let mut pending: Vec<N> = nodes.into_iter().collect();
+    pending.reverse();
+    while let Some(node) = pending.pop() {
+        if edges.contains_key(&node) {
+            continue;
+        }
+        let next: Vec<N> = successors(&node).into_iter().collect();
+        pending.extend(next.iter().rev().cloned());
+        edges.insert(node.clone(), next);
+        found.push(node);
+    }
+
+    let mut incoming: HashMap<&N, usize> = found.iter().map(|n| (n, 0)).collect();
+    for next in edges.values().flatten() {
+        *incoming.entry(next).or_default() += 1;
+    }
+
+    let mut ready: VecDeque<&N> = found.iter().filter(|n| incoming[n] == 0).collect();
+    let mut order: Vec<N> = Vec::with_capacity(found.len());
+    while let Some(node) = ready.pop_front() {
+        order.push(node.clone());
+        for next in &edges[node] {
+            let count = incoming.get_mut(next).expect("every successor was found");
+            *count -= 1;
+            if *count == 0 {
+                ready.push_back(next);
+            }
+        }
+    }
+
+    if order.len() == found.len() {
+        return Ok(order);
+    }
+
+    let stuck = found.iter().filter(|n| incoming[n] > 0).cloned();
+    Err(find_cycle(stuck, adjacency(&edges)).expect("nodes left unordered are on a cycle"))
+}
+
+// a cycle among the nodes reachable from `nodes`, starting and ending at the
+// same node but listing it once
+pub fn find_cycle<N, I>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    // false while a node is on the current path, true once all its successors are done
+    let mut done: HashMap<N, bool> = HashMap::new();
+
+    for root in nodes {
+        if done.contains_key(&root) {
+            continue;
+        }
+
+        done.insert(root.clone(), false);
+        let next: Vec<N> = successors(&root).into_iter().collect();
+        let mut path: Vec<(N, Vec<N>)> = vec![(root, next)];
+
+        while let Some((_, pending)) = path.last_mut() {
+            let Some(next) = pending.pop() else {
+                let (node, _) = path.pop().expect("path is not empty");
+                done.insert(node, true);
+                continue;
+            };
+
+            match done.get(&next) {
+                Some(false) => {
+                    let start = path.iter().position(|(n, _)| *n == next).unwrap_or(0);
+                    return Some(path.drain(start..).map(|(n, _)| n).collect());
+                }
+                Some(true) => {}
+                None => {
+                    done.insert(next.clone(), false);
+                    let after: Vec<N> = successors(&next).into_iter().collect();
+                    path.push((next, after));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+// the number of different paths from `start` that end at a node where `goal`
+// holds, or a cycle if there could be endlessly many
+pub fn count_paths<N, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut goal: impl FnMut(&N) -> bool,
+) -> Result<usize, Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let order = toposort([start.clone()], &mut successors)?;
+
+    // working back from the end, each node's successors are already counted
+    let mut paths: HashMap<N, usize> = HashMap::with_capacity(order.len());
+    for node in order.into_iter().rev() {
+        let count = if goal(&node) {
+            1
+        } else {
+            successors(&node).into_iter().map(|next| paths[&next]).sum()
+        };
+        paths.insert(node, count);
+    }
+
+    Ok(paths[&start])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{adjacency, astar, bfs, components, count_paths, dijkstra, find_cycle, toposort};
+    use std::collections::{HashMap, HashSet};
+
+    fn graph(edges: &[(char, char)]) -> HashMap<char, Vec<char>> {
+        let mut graph: HashMap<char, Vec<char>> = HashMap::new();
+        for &(from, to) in edges {
+            graph.entry(from).or_default().push(to);
+        }
+        graph
+    }
+
+    #[test]
+    fn test_bfs() {
+        let g = graph(&[('a', 'b'), ('b', 'c'), ('a', 'c'), ('c', 'd'), ('e', 'a')]);
+        let distances = bfs(['a'], adjacency(&g));
+        assert_eq!(distances.len(), 4);
+        assert_eq!((distances[&'c'], distances[&'d']), (1, 2));
+
+        // a line, found from both ends at once
+        let line = bfs([0, 10], |&n: &i64| {
+            [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n))
+        });
+        assert_eq!(line[&4], 4);
+        assert_eq!(line[&7], 3);
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let costs: HashMap<char, Vec<(char, u32)>> = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('c', vec![('b', 3), ('d', 8)]),
+            ('b', vec![('d', 1)]),
+        ]);
+        let successors = |n: &char| costs.get(n).cloned().unwrap_or_default();
+
+        assert_eq!(
+            dijkstra('a', successors, |&n| n == 'd'),
+            Some((6, vec!['a', 'c', 'b', 'd']))
+        );
+        assert_eq!(dijkstra('d', successors, |&n| n == 'a'), None);
+
+        // manhattan distance on an open grid with a wall at x = 2 for y < 4
+        let goal = (4i64, 0i64);
+        let neighbours = |&(x, y): &(i64, i64)| {
+            [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && !(x == 2 && y < 4))
+                .map(|p| (p, 1))
+                .collect::<Vec<_>>()
+        };
+        let (cost, path) = astar(
+            (0, 0),
+            neighbours,
+            |&(x, y)| (goal.0 - x).abs() + (goal.1 - y).abs(),
+            |&p| p == goal,
+        )
+        .unwrap();
+        assert_eq!(cost, 12);
+        assert_eq!(path.len(), 13);
+        assert!(path.contains(&(2, 4)));
+    }
+
+    #[test]
+    fn test_components() {
+        let g = graph(&[('a', 'b'), ('b', 'a'), ('c', 'd'), ('d', 'c')]);
+        let mut found: Vec<Vec<char>> = components(['a', 'b', 'c', 'd', 'e'], adjacency(&g))
+            .into_iter()
+            .map(|c| {
+                let mut c: Vec<char> = c.into_iter().collect();
+                c.sort();
+                c
+            })
+            .collect();
+        found.sort();
+        assert_eq!(found, vec![vec!['a', 'b'], vec!['c', 'd'], vec!['e']]);
+    }
+
+    #[test]
+    fn test_toposort_and_cycles() {
+        let g = graph(&[('a', 'b'), ('a', 'c'), ('b', 'd'), ('c', 'd')]);
+        let order = toposort(['a'], adjacency(&g)).unwrap();
+        let position: HashMap<char, usize> =
+            order.iter().enumerate().map(|(i, &n)| (n, i)).collect();
+        assert_eq!(order.len(), 4);
+        assert!(position[&'a'] < position[&'b'] && position[&'b'] < position[&'d']);
+        assert!(position[&'c'] < position[&'d']);
+        assert_eq!(find_cycle(['a'], adjacency(&g)), None);
+        assert_eq!(count_paths('a', adjacency(&g), |&n| n == 'd'), Ok(2));
+
+        let g = graph(&[('a', 'b'), ('b', 'c'), ('c', 'd'), ('d', 'b')]);
+        let cycle: HashSet<char> = toposort(['a'], adjacency(&g))
+            .unwrap_err()
+            .into_iter()
+            .collect();
+        assert_eq!(cycle, HashSet::from(['b', 'c', 'd']));
+        assert_eq!(find_cycle(['a'], adjacency(&g)).map(|c| c.len()), Some(3));
+        assert!(count_paths('a', adjacency(&g), |&n| n == 'd').is_err());
+    }
+}
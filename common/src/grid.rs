@@ -0,0 +1,348 @@
+use crate::input::{parse_grid_row, read_lines};
+use crate::{Diagnostic, Error, Source};
+use std::ops::{Index, IndexMut};
+
+// positions are (x, y) with y counting rows down from the top
+pub type Pos = (usize, usize);
+
+pub const ORTHOGONAL: [(i64, i64); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+pub const ADJACENT: [(i64, i64); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// a rectangular grid stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    // rows must all have the same length
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    // one row per line, with `cell` turning each character into a cell. The
+    // grid can't be empty.
+    pub fn parse(source: Source, cell: impl Fn(char) -> Option<T>) -> Result<Grid<T>, Error> {
+        let path = source.name().to_string();
+        let lines = read_lines(source)?;
+        let width = lines.first().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            let line = lines.first().map_or("", String::as_str);
+            return Err(Error::Parse(Diagnostic::new(
+                &path,
+                1,
+                line,
+                line,
+                "expected a row of cells",
+            )));
+        }
+
+        let mut cells = Vec::with_capacity(width * lines.len());
+        for (idx, line) in lines.iter().enumerate() {
+            let row = parse_grid_row(&path, idx + 1, line, &cell)?;
+            if row.len() != width {
+                let message = format!(
+                    "expected {} cells like the first row, found {}",
+                    width,
+                    row.len()
+                );
+                return Err(Error::Parse(Diagnostic::new(
+                    &path,
+                    idx + 1,
+                    line,
+                    line,
+                    message,
+                )));
+            }
+            cells.extend(row);
+        }
+
+        Ok(Grid {
+            width,
+            height: lines.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Pos) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Pos) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    // the grid repeated forever in both directions, panicking if it's empty
+    pub fn wrapped(&self, (x, y): Pos) -> &T {
+        &self[(x % self.width, y % self.height)]
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |idx| (idx % width, idx / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn cells_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // the position one step of (dx, dy) away, if it is inside the grid
+    pub fn offset(&self, (x, y): Pos, (dx, dy): (i64, i64)) -> Option<Pos> {
+        let x = usize::try_from(x as i64 + dx).ok()?;
+        let y = usize::try_from(y as i64 + dy).ok()?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    // up, left, right and down neighbours inside the grid
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    // orthogonal and diagonal neighbours inside the grid
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ADJACENT.iter().filter_map(move |&d| self.offset(pos, d))
+    }
+
+    // every position from `pos` in steps of `dir` until the edge, not
+    // including `pos` itself
+    pub fn ray(&self, pos: Pos, dir: (i64, i64)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.offset(pos, dir), move |&p| self.offset(p, dir))
+    }
+
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Pos) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Pos) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+// a grid that is updated a generation at a time: each step reads every cell
+// of the current generation and writes the next one into a second grid, which
+// then becomes current, so no cell sees a neighbour's new value and nothing is
+// allocated per step
+#[derive(Debug, Clone)]
+pub struct Buffered<T> {
+    front: Grid<T>,
+    back: Grid<T>,
+}
+
+impl<T: Clone + PartialEq> Buffered<T> {
+    pub fn new(grid: Grid<T>) -> Buffered<T> {
+        Buffered {
+            back: grid.clone(),
+            front: grid,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.front
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.front
+    }
+
+    // applies `rule` to every cell and returns how many cells changed
+    pub fn step(&mut self, rule: impl Fn(&Grid<T>, Pos, &T) -> T) -> usize {
+        let mut changes = 0;
+        for (idx, pos) in self.front.positions().enumerate() {
+            let cell = &self.front.cells[idx];
+            let next = rule(&self.front, pos, cell);
+            if next != *cell {
+                changes += 1;
+            }
+            self.back.cells[idx] = next;
+        }
+
+        std::mem::swap(&mut self.front, &mut self.back);
+        changes
+    }
+
+    // steps until nothing changes, returning the number of steps that changed something
+    pub fn settle(&mut self, rule: impl Fn(&Grid<T>, Pos, &T) -> T) -> usize {
+        let mut steps = 0;
+        while self.step(&rule) > 0 {
+            steps += 1;
+        }
+        steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Buffered, Grid};
+    use crate::{Error, Source};
+
+    fn grid() -> Grid<char> {
+        Grid::parse(Source::text("abc\ndef\n"), Some).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = grid();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'f');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.rows().nth(1), Some(&['d', 'e', 'f'][..]));
+
+        match Grid::parse(Source::text("ab\nabc\n"), Some) {
+            Err(Error::Parse(d)) => assert_eq!((d.line, d.snippet.as_str()), (2, "abc")),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let digits = Grid::parse(Source::text("12\n3x\n"), |c| c.to_digit(10));
+        match digits {
+            Err(Error::Parse(d)) => assert_eq!((d.line, d.column), (2, 2)),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        for text in ["", "\n", "\nab\n"] {
+            match Grid::parse(Source::text(text), Some) {
+                Err(Error::Parse(d)) => assert_eq!(d.line, 1),
+                other => panic!("{:?}: unexpected {:?}", text, other),
+            }
+        }
+
+        assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = grid();
+        let mut four: Vec<char> = grid.neighbours4((0, 0)).map(|p| grid[p]).collect();
+        four.sort();
+        assert_eq!(four, vec!['b', 'd']);
+
+        let eight: Vec<char> = grid.neighbours8((1, 1)).map(|p| grid[p]).collect();
+        assert_eq!(eight, vec!['a', 'b', 'c', 'd', 'f']);
+    }
+
+    #[test]
+    fn test_ray_and_wrapping() {
+        let grid = grid();
+        let ray: Vec<char> = grid.ray((0, 0), (1, 0)).map(|p| grid[p]).collect();
+        assert_eq!(ray, vec!['b', 'c']);
+        assert_eq!(grid.ray((2, 1), (1, 1)).count(), 0);
+
+        assert_eq!(*grid.wrapped((4, 3)), 'e');
+    }
+
+    #[test]
+    fn test_buffered() {
+        // a blinker oscillates between a row and a column
+        let mut life = Buffered::new(
+            Grid::parse(Source::text(".....\n.....\n.###.\n.....\n.....\n"), |c| {
+                Some(c == '#')
+            })
+            .unwrap(),
+        );
+        let rule = |grid: &Grid<bool>, pos, &alive: &bool| {
+            let n = grid.neighbours8(pos).filter(|&p| grid[p]).count();
+            n == 3 || (alive && n == 2)
+        };
+
+        assert_eq!(life.step(rule), 4);
+        let column: Vec<_> = life
+            .grid()
+            .iter()
+            .filter(|(_, &c)| c)
+            .map(|(p, _)| p)
+            .collect();
+        assert_eq!(column, vec![(2, 1), (2, 2), (2, 3)]);
+
+        life.step(rule);
+        assert!(life.grid()[(1, 2)]);
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{Diagnostic, Error, Sections, Source};
+
+use regex::{Captures, Regex};
+use std::io::{BufRead, Read};
+use std::str::FromStr;
+
+pub fn read_to_string(mut source: Source) -> Result<String, Error> {
+    let mut contents = String::new();
+    source.read_to_string(&mut contents).map_err(Error::IO)?;
+
+    Ok(contents)
+}
+
+pub fn read_lines(source: Source) -> Result<Vec<String>, Error> {
+    source.lines().map(|line| line.map_err(Error::IO)).collect()
+}
+
+pub fn parse_int_per_line<T>(source: Source) -> Result<Vec<T>, Error>
+where
+    T: FromStr<Err = std::num::ParseIntError>,
+{
+    let path = source.name().to_string();
+    read_lines(source)?
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            line.trim().parse::<T>().map_err(|e| {
+                Error::Parse(Diagnostic::new(
+                    &path,
+                    idx + 1,
+                    line,
+                    line.trim(),
+                    e.to_string(),
+                ))
+            })
+        })
+        .collect()
+}
+
+pub fn parse_comma_separated_ints<T>(source: Source) -> Result<Vec<T>, Error>
+where
+    T: FromStr<Err = std::num::ParseIntError>,
+{
+    let path = source.name().to_string();
+    let mut ints = Vec::new();
+    for (idx, line) in read_lines(source)?.iter().enumerate() {
+        for s in line.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let int = s.parse::<T>().map_err(|e| {
+                Error::Parse(Diagnostic::new(&path, idx + 1, line, s, e.to_string()))
+            })?;
+            ints.push(int);
+        }
+    }
+
+    Ok(ints)
+}
+
+pub fn parse_grid<T>(
+    source: Source,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, Error> {
+    let path = source.name().to_string();
+    read_lines(source)?
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| parse_grid_row(&path, idx + 1, line, &cell))
+        .collect()
+}
+
+// one row of a grid, pointing at the first character `cell` rejects
+pub(crate) fn parse_grid_row<T>(
+    path: &str,
+    line_number: usize,
+    line: &str,
+    cell: impl Fn(char) -> Option<T>,
+) -> Result<Vec<T>, Error> {
+    line.chars()
+        .enumerate()
+        .map(|(col, c)| {
+            cell(c).ok_or_else(|| {
+                let message = format!("unexpected character {:?}", c);
+                Error::Parse(Diagnostic::at(path, line_number, line, col + 1, 1, message))
+            })
+        })
+        .collect()
+}
+
+pub fn parse_char_grid(source: Source) -> Result<Vec<Vec<char>>, Error> {
+    parse_grid(source, Some)
+}
+
+pub fn parse_digit_grid<T: From<u8>>(source: Source) -> Result<Vec<Vec<T>>, Error> {
+    parse_grid(source, |c| c.to_digit(10).map(|d| T::from(d as u8)))
+}
+
+// groups of lines separated by one or more blank lines
+pub fn parse_groups(source: Source) -> Result<Vec<Vec<String>>, Error> {
+    Sections::read(source).map(Sections::into_vec)
+}
+
+pub fn parse_regex_records<T>(
+    source: Source,
+    pattern: &str,
+    record: impl Fn(&Captures) -> Result<T, Error>,
+) -> Result<Vec<T>, Error> {
+    let rgx = Regex::new(pattern).map_err(Error::Regex)?;
+    let path = source.name().to_string();
+
+    read_lines(source)?
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let diagnostic = |message: String| Diagnostic::new(&path, idx + 1, line, line, message);
+            let captures = rgx.captures(line).ok_or_else(|| {
+                Error::Parse(diagnostic(format!("line does not match {}", pattern)))
+            })?;
+            record(&captures).map_err(|e| match e {
+                Error::Parse(_) => e,
+                _ => Error::Parse(diagnostic(e.to_string())),
+            })
+        })
+        .collect()
+}
+
+pub fn capture_str<'a>(captures: &Captures<'a>, idx: usize) -> Result<&'a str, Error> {
+    captures
+        .get(idx)
+        .map(|m| m.as_str())
+        .ok_or(Error::NoCapture(captures[0].to_string()))
+}
+
+pub fn capture_int<T>(captures: &Captures, idx: usize) -> Result<T, Error>
+where
+    T: FromStr<Err = std::num::ParseIntError>,
+{
+    capture_str(captures, idx)?
+        .parse::<T>()
+        .map_err(Error::ParseInt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_int_per_line() {
+        let ret = parse_int_per_line::<i64>(Source::file("ints_test.txt"));
+        assert_eq!(ret.unwrap(), vec![1721, 979, -366]);
+    }
+
+    #[test]
+    fn test_parse_comma_separated_ints() {
+        let ret = parse_comma_separated_ints::<usize>(Source::file("comma_test.txt"));
+        assert_eq!(ret.unwrap(), vec![3, 4, 3, 1, 2, 16, 1]);
+    }
+
+    #[test]
+    fn test_parse_grids() {
+        let chars = parse_char_grid(Source::file("grid_test.txt")).unwrap();
+        assert_eq!(chars[0], vec!['2', '1', '9', '9']);
+        assert_eq!(chars.len(), 3);
+
+        let digits = parse_digit_grid::<i64>(Source::file("grid_test.txt")).unwrap();
+        assert_eq!(digits[2], vec![9, 8, 5, 6]);
+
+        let err = parse_grid(Source::file("grid_test.txt"), |c| {
+            if c == '9' {
+                None
+            } else {
+                Some(c)
+            }
+        });
+        match err {
+            Err(Error::Parse(d)) => {
+                assert_eq!((d.line, d.column, d.snippet.as_str()), (1, 3, "2199"))
+            }
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_parse_groups() {
+        let groups = parse_groups(Source::file("groups_test.txt")).unwrap();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[0], vec!["abc"]);
+        assert_eq!(groups[1], vec!["a", "b"]);
+        assert_eq!(groups[2], vec!["ab", "ac"]);
+    }
+
+    #[test]
+    fn test_parse_regex_records() {
+        let records = parse_regex_records(
+            Source::file("records_test.txt"),
+            r"([0-9]+)-([0-9]+) ([a-z]): ([a-z]+)",
+            |captures| -> Result<(usize, usize, String), Error> {
+                Ok((
+                    capture_int(captures, 1)?,
+                    capture_int(captures, 2)?,
+                    capture_str(captures, 4)?.to_string(),
+                ))
+            },
+        )
+        .unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[1], (1, 3, "cdefg".to_string()));
+
+        let err = parse_regex_records(Source::file("ints_test.txt"), r"([a-z]+)", |_| Ok(()));
+        match err {
+            Err(Error::Parse(d)) => assert_eq!((d.line, d.snippet.as_str()), (1, "1721")),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_parse_int_diagnostics() {
+        match parse_comma_separated_ints::<usize>(Source::file("comma_bad_test.txt")) {
+            Err(Error::Parse(d)) => assert_eq!((d.line, d.column, d.width), (2, 4, 2)),
+            res => panic!("unexpected {:?}", res),
+        }
+
+        match parse_int_per_line::<u8>(Source::file("records_test.txt")) {
+            Err(Error::Parse(d)) => {
+                assert_eq!(
+                    (d.path.as_str(), d.line, d.column),
+                    ("records_test.txt", 1, 1)
+                )
+            }
+            res => panic!("unexpected {:?}", res),
+        }
+
+        match parse_int_per_line::<i64>(Source::text("12\n1x\n")) {
+            Err(Error::Parse(d)) => assert_eq!((d.path.as_str(), d.line), ("<input>", 2)),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+pub mod automaton;
+pub mod cache;
+mod diagnostic;
+mod error;
+pub mod expr;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod number;
+pub mod ocr;
+#[cfg(feature = "render")]
+pub mod render;
+pub mod sections;
+pub mod solution;
+mod source;
+
+pub use cache::InputCache;
+pub use diagnostic::Diagnostic;
+
+pub use error::Error;
+
+pub use grid::Grid;
+
+pub use sections::Sections;
+
+pub use solution::Solution;
+pub use source::Source;
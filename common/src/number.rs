@@ -0,0 +1,285 @@
+use crate::Error;
+pub use num_bigint::BigInt;
+use std::fmt;
+
+// a tally that may outgrow u64, such as the number of ways to do something.
+// Arithmetic on it fails with Error::Arithmetic once a u64 overflows, unless
+// the bigint feature is enabled, when it grows without limit instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Count(Tally);
+
+#[cfg(not(feature = "bigint"))]
+mod tally {
+    pub type Tally = u64;
+
+    pub fn from(n: u64) -> Tally {
+        n
+    }
+
+    pub fn add(a: &u64, b: &u64) -> Option<u64> {
+        a.checked_add(*b)
+    }
+
+    pub fn sub(a: &u64, b: &u64) -> Option<u64> {
+        a.checked_sub(*b)
+    }
+
+    pub fn mul(a: &u64, b: &u64) -> Option<u64> {
+        a.checked_mul(*b)
+    }
+}
+
+#[cfg(feature = "bigint")]
+mod tally {
+    pub type Tally = num_bigint::BigUint;
+
+    pub fn from(n: u64) -> Tally {
+        n.into()
+    }
+
+    pub fn add(a: &Tally, b: &Tally) -> Option<Tally> {
+        Some(a + b)
+    }
+
+    pub fn sub(a: &Tally, b: &Tally) -> Option<Tally> {
+        (a >= b).then(|| a - b)
+    }
+
+    pub fn mul(a: &Tally, b: &Tally) -> Option<Tally> {
+        Some(a * b)
+    }
+}
+
+use tally::Tally;
+
+impl Count {
+    pub fn checked_add(&self, other: &Count) -> Result<Count, Error> {
+        self.apply(other, '+', tally::add)
+    }
+
+    // fails rather than going below zero, in either mode
+    pub fn checked_sub(&self, other: &Count) -> Result<Count, Error> {
+        self.apply(other, '-', tally::sub)
+    }
+
+    pub fn checked_mul(&self, other: &Count) -> Result<Count, Error> {
+        self.apply(other, '*', tally::mul)
+    }
+
+    pub fn sum<'a>(counts: impl IntoIterator<Item = &'a Count>) -> Result<Count, Error> {
+        counts
+            .into_iter()
+            .try_fold(Count::default(), |total, count| total.checked_add(count))
+    }
+
+    fn apply(
+        &self,
+        other: &Count,
+        symbol: char,
+        op: fn(&Tally, &Tally) -> Option<Tally>,
+    ) -> Result<Count, Error> {
+        op(&self.0, &other.0)
+            .map(Count)
+            .ok_or_else(|| Error::Arithmetic(format!("{} {} {}", self, symbol, other)))
+    }
+}
+
+impl From<u64> for Count {
+    fn from(n: u64) -> Count {
+        Count(tally::from(n))
+    }
+}
+
+impl fmt::Display for Count {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub fn gcd(a: i64, b: i64) -> i64 {
+    extended_gcd(a, b).0
+}
+
+// (g, x, y) where g is the non-negative greatest common divisor of a and b,
+// and a * x + b * y = g
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+// x in 0..m with a * x ≡ 1 (mod m), if a and m are coprime. Fails unless m
+// is positive.
+pub fn mod_inverse(a: i64, m: i64) -> Result<Option<i64>, Error> {
+    if m <= 0 {
+        return Err(Error::Modulus(m));
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(m), m);
+    Ok(if g == 1 { Some(x.rem_euclid(m)) } else { None })
+}
+
+// base^exp mod m, in 0..m. Fails unless m is positive.
+pub fn mod_pow(base: i64, exp: u64, m: i64) -> Result<i64, Error> {
+    if m <= 0 {
+        return Err(Error::Modulus(m));
+    }
+
+    let m = m as i128;
+    let mut base = (base as i128).rem_euclid(m);
+    let mut exp = exp;
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    Ok(result as i64)
+}
+
+// the smallest non-negative x with x ≡ residue (mod modulus) for every
+// (residue, modulus), together with the modulus the solution repeats with.
+// Moduli need not be coprime, but the congruences must then agree where
+// they overlap.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Result<(BigInt, BigInt), Error> {
+    let mut x = BigInt::from(0);
+    let mut modulus = BigInt::from(1);
+
+    for (residue, m) in congruences {
+        if m <= 0 {
+            return Err(Error::Modulus(m));
+        }
+
+        // x + modulus * k ≡ residue (mod m), so solve modulus * k ≡ diff (mod m)
+        let small = |n: BigInt| -> i64 {
+            let n: i64 = (n % m).try_into().expect("reduced below an i64 modulus");
+            n.rem_euclid(m)
+        };
+        let step = small(modulus.clone());
+        let diff = small(BigInt::from(residue) - &x);
+
+        let (g, inverse, _) = extended_gcd(step, m);
+        if diff % g != 0 {
+            return Err(Error::Inconsistent {
+                residue,
+                modulus: m,
+            });
+        }
+
+        let k = ((diff / g) as i128 * inverse as i128).rem_euclid((m / g) as i128);
+        x += &modulus * k;
+        modulus *= m / g;
+    }
+
+    Ok((x, modulus))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crt, extended_gcd, gcd, mod_inverse, mod_pow, BigInt, Count};
+    use crate::Error;
+
+    #[test]
+    fn test_gcd() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(-12, 18), 6);
+        assert_eq!(gcd(0, 5), 5);
+
+        for (a, b) in [(240, 46), (-7, 3), (17, 0), (1_000_000_007, 998_244_353)] {
+            let (g, x, y) = extended_gcd(a, b);
+            assert_eq!(a * x + b * y, g);
+        }
+    }
+
+    #[test]
+    fn test_modular() {
+        assert_eq!(mod_inverse(3, 11).unwrap(), Some(4));
+        assert_eq!(mod_inverse(-3, 11).unwrap(), Some(7));
+        assert_eq!(mod_inverse(6, 9).unwrap(), None);
+        assert!(matches!(mod_inverse(3, 0), Err(Error::Modulus(0))));
+        assert!(matches!(mod_inverse(3, -11), Err(Error::Modulus(-11))));
+
+        assert_eq!(mod_pow(4, 13, 497).unwrap(), 445);
+        assert_eq!(mod_pow(-2, 3, 5).unwrap(), 2);
+        assert_eq!(mod_pow(7, 0, 1).unwrap(), 0);
+        assert!(matches!(mod_pow(4, 13, 0), Err(Error::Modulus(0))));
+        assert!(matches!(mod_pow(4, 13, -497), Err(Error::Modulus(-497))));
+        assert_eq!(mod_pow(20201227, 1 << 40, 1_000_000_007).unwrap(), {
+            // repeated squaring the long way round
+            let mut n: i64 = 20201227;
+            for _ in 0..40 {
+                n = (n as i128 * n as i128 % 1_000_000_007) as i64;
+            }
+            n
+        });
+    }
+
+    #[test]
+    fn test_crt() {
+        assert_eq!(
+            crt([(2, 3), (3, 5), (2, 7)]).unwrap(),
+            (BigInt::from(23), BigInt::from(105))
+        );
+
+        // 4 and 6 share a factor of 2, on which 2 and 4 agree
+        assert_eq!(
+            crt([(2, 4), (4, 6)]).unwrap(),
+            (BigInt::from(10), BigInt::from(12))
+        );
+        assert_eq!(
+            crt([(-1, 5), (9, 5)]).unwrap(),
+            (BigInt::from(4), BigInt::from(5))
+        );
+
+        match crt([(1, 4), (2, 6)]) {
+            Err(Error::Inconsistent { residue, modulus }) => assert_eq!((residue, modulus), (2, 6)),
+            other => panic!("unexpected {:?}", other),
+        }
+        assert!(matches!(crt([(1, 0)]), Err(Error::Modulus(0))));
+
+        // a modulus far beyond i64
+        let congruences = [(5, 1_000_000_007), (7, 998_244_353), (11, 1_000_000_009)];
+        let (x, modulus) = crt(congruences).unwrap();
+        assert_eq!(
+            modulus,
+            BigInt::from(1_000_000_007i64) * 998_244_353 * 1_000_000_009
+        );
+        for (residue, m) in congruences {
+            assert_eq!(&x % m, BigInt::from(residue));
+        }
+    }
+
+    #[test]
+    fn test_count() {
+        let (two, three) = (Count::from(2), Count::from(3));
+        assert_eq!(two.checked_add(&three).unwrap(), Count::from(5));
+        assert_eq!(three.checked_sub(&two).unwrap(), Count::from(1));
+        assert_eq!(two.checked_mul(&three).unwrap().to_string(), "6");
+        assert_eq!(Count::sum([&two, &three, &two]).unwrap(), Count::from(7));
+        match two.checked_sub(&three) {
+            Err(Error::Arithmetic(expr)) => assert_eq!(expr, "2 - 3"),
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let max = Count::from(u64::MAX);
+        let sum = max.checked_add(&max);
+        #[cfg(not(feature = "bigint"))]
+        assert!(matches!(sum, Err(Error::Arithmetic(_))));
+        #[cfg(feature = "bigint")]
+        assert_eq!(sum.unwrap().to_string(), "36893488147419103230");
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{Error, Grid};
+use std::collections::HashSet;
+
+// the capital letters puzzles draw in dots, 4 wide and 6 tall with a blank
+// column between letters
+const FONT_6: [(char, &str); 18] = [
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+// the larger 6 wide, 10 tall letters
+const FONT_10: [(char, &str); 15] = [
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+// reads the letters drawn by a set of dots at (x, y), wherever they are
+pub fn read_points(points: &HashSet<(i64, i64)>) -> Result<String, Error> {
+    let (Some(min_x), Some(min_y)) = (
+        points.iter().map(|&(x, _)| x).min(),
+        points.iter().map(|&(_, y)| y).min(),
+    ) else {
+        return Ok(String::new());
+    };
+    let max_x = points.iter().map(|&(x, _)| x).max().unwrap_or(min_x);
+    let max_y = points.iter().map(|&(_, y)| y).max().unwrap_or(min_y);
+
+    let mut grid = Grid::new(
+        (max_x - min_x + 1) as usize,
+        (max_y - min_y + 1) as usize,
+        false,
+    );
+    for &(x, y) in points {
+        grid[((x - min_x) as usize, (y - min_y) as usize)] = true;
+    }
+
+    read_grid(&grid)
+}
+
+// reads the letters drawn by the set cells of a grid, which may have blank
+// rows and columns around them
+pub fn read_grid(grid: &Grid<bool>) -> Result<String, Error> {
+    let lit_rows: Vec<usize> = (0..grid.height())
+        .filter(|&y| (0..grid.width()).any(|x| grid[(x, y)]))
+        .collect();
+    let (Some(&top), Some(&bottom)) = (lit_rows.first(), lit_rows.last()) else {
+        return Ok(String::new());
+    };
+
+    let font: &[(char, &str)] = match bottom - top + 1 {
+        6 => &FONT_6,
+        10 => &FONT_10,
+        height => return Err(Error::GlyphHeight(height)),
+    };
+
+    // letters are separated by at least one blank column
+    let lit = |x: usize| (top..=bottom).any(|y| grid[(x, y)]);
+    let mut letters = String::new();
+    let mut x = 0;
+    while x < grid.width() {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+
+        let start = x;
+        while x < grid.width() && lit(x) {
+            x += 1;
+        }
+
+        let bitmap = (top..=bottom)
+            .map(|y| {
+                (start..x)
+                    .map(|x| if grid[(x, y)] { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        match font.iter().find(|(_, glyph)| *glyph == bitmap) {
+            Some(&(letter, _)) => letters.push(letter),
+            None => return Err(Error::UnknownGlyph(bitmap)),
+        }
+    }
+
+    Ok(letters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_grid, read_points, FONT_10, FONT_6};
+    use crate::{Error, Grid, Source};
+    use std::collections::HashSet;
+
+    fn picture(text: &str) -> Grid<bool> {
+        Grid::parse(Source::text(text), |c| Some(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn test_read_grid() {
+        let hi = picture(
+            "...........\n\
+             .#..#.###..\n\
+             .#..#..#...\n\
+             .####..#...\n\
+             .#..#..#...\n\
+             .#..#..#...\n\
+             .#..#.###..\n",
+        );
+        assert_eq!(read_grid(&hi).unwrap(), "HI");
+
+        // every letter of both fonts reads back as itself
+        for font in [&FONT_6[..], &FONT_10[..]] {
+            let text: String = font.iter().map(|&(c, _)| c).collect();
+            let rows: Vec<String> = (0..font[0].1.lines().count())
+                .map(|y| {
+                    font.iter()
+                        .map(|(_, glyph)| glyph.lines().nth(y).unwrap())
+                        .collect::<Vec<_>>()
+                        .join(".")
+                })
+                .collect();
+            assert_eq!(read_grid(&picture(&rows.join("\n"))).unwrap(), text);
+        }
+    }
+
+    #[test]
+    fn test_read_points() {
+        let points: HashSet<(i64, i64)> = FONT_6[10]
+            .1
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.char_indices()
+                    .filter(|&(_, c)| c == '#')
+                    .map(move |(x, _)| (x as i64 - 20, y as i64 + 7))
+            })
+            .collect();
+        assert_eq!(read_points(&points).unwrap(), "L");
+        assert_eq!(read_points(&HashSet::new()).unwrap(), "");
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let grid = picture("#..#\n#..#\n#..#\n#..#\n.##.\n.##.\n");
+        match read_grid(&grid) {
+            Err(Error::UnknownGlyph(bitmap)) => {
+                assert_eq!(bitmap, "#..#\n#..#\n#..#\n#..#\n.##.\n.##.")
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        match read_grid(&picture("#\n#\n")) {
+            Err(Error::GlyphHeight(2)) => {}
+            other => panic!("unexpected {:?}", other),
+        }
+    }
+}
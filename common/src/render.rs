@@ -0,0 +1,363 @@
+use crate::automaton::Point;
+use crate::{Grid, Solution};
+use std::borrow::Cow;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::Path;
+
+#[derive(Debug)]
+pub enum Error {
+    IO(std::io::Error),
+    Gif(gif::EncodingError),
+    Png(png::EncodingError),
+    Empty,
+    TooLarge(usize, usize),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{}", e),
+            Error::Gif(e) => write!(f, "{}", e),
+            Error::Png(e) => write!(f, "{}", e),
+            Error::Empty => write!(f, "nothing was recorded"),
+            Error::TooLarge(width, height) => {
+                write!(f, "{}x{} is too large for a GIF", width, height)
+            }
+        }
+    }
+}
+
+// days whose simulations can be recorded a generation at a time
+pub trait Animated: Solution {
+    fn record(input: &Self::Input, part: u8) -> Recorder;
+}
+
+// a picture where each pixel is an index into the recorder's palette
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<u8>,
+}
+
+impl Frame {
+    pub fn new(width: usize, height: usize) -> Frame {
+        Frame {
+            width,
+            height,
+            pixels: vec![0; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, colour: u8) {
+        self.pixels[y * self.width + x] = colour;
+    }
+
+    // one pixel per cell
+    pub fn grid<T>(grid: &Grid<T>, colour: impl Fn(&T) -> u8) -> Frame {
+        Frame {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.cells().map(colour).collect(),
+        }
+    }
+}
+
+// generations of cells in D dimensions, drawn as 2D slices through the first
+// two axes: the third axis runs across the picture and any others down it.
+// Every generation is drawn with the same bounds so the slices stay put as the
+// pattern grows.
+#[derive(Debug, Clone, Default)]
+pub struct Slices<const D: usize> {
+    generations: Vec<Vec<Point<D>>>,
+}
+
+impl<const D: usize> Slices<D> {
+    pub fn new() -> Slices<D> {
+        Slices {
+            generations: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, live: Vec<Point<D>>) {
+        self.generations.push(live);
+    }
+
+    // pixels between slices are colour 0
+    pub fn frames(&self, dead: u8, alive: u8) -> Vec<Frame> {
+        let mut points = self.generations.iter().flatten();
+        let Some(&first) = points.next() else {
+            return vec![Frame::new(0, 0); self.generations.len()];
+        };
+
+        let (mut min, mut max) = (first, first);
+        for p in points {
+            for axis in 0..D {
+                min[axis] = min[axis].min(p[axis]);
+                max[axis] = max[axis].max(p[axis]);
+            }
+        }
+        let size: Vec<usize> = (0..D)
+            .map(|axis| (max[axis] - min[axis] + 1) as usize)
+            .collect();
+
+        let (slice_width, slice_height) = (size[0], size.get(1).copied().unwrap_or(1));
+        let columns = size.get(2).copied().unwrap_or(1);
+        let rows: usize = size.iter().skip(3).product();
+
+        // the tile a point is drawn in
+        let tile = |p: &Point<D>| {
+            let column = if D > 2 { (p[2] - min[2]) as usize } else { 0 };
+            let row = (3..D).fold(0, |row, axis| {
+                row * size[axis] + (p[axis] - min[axis]) as usize
+            });
+            (column, row)
+        };
+
+        let mut blank = Frame::new(
+            columns * (slice_width + 1) - 1,
+            rows * (slice_height + 1) - 1,
+        );
+        for column in 0..columns {
+            for row in 0..rows {
+                for y in 0..slice_height {
+                    for x in 0..slice_width {
+                        blank.set(
+                            column * (slice_width + 1) + x,
+                            row * (slice_height + 1) + y,
+                            dead,
+                        );
+                    }
+                }
+            }
+        }
+
+        self.generations
+            .iter()
+            .map(|live| {
+                let mut frame = blank.clone();
+                for p in live {
+                    let (column, row) = tile(p);
+                    let y = if D > 1 { (p[1] - min[1]) as usize } else { 0 };
+                    frame.set(
+                        column * (slice_width + 1) + (p[0] - min[0]) as usize,
+                        row * (slice_height + 1) + y,
+                        alive,
+                    );
+                }
+                frame
+            })
+            .collect()
+    }
+}
+
+// collects frames and writes them out as an animated GIF or numbered PNGs
+#[derive(Debug, Clone)]
+pub struct Recorder {
+    palette: Vec<[u8; 3]>,
+    scale: usize,
+    delay: u16,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new(palette: &[[u8; 3]]) -> Recorder {
+        Recorder {
+            palette: palette.to_vec(),
+            scale: 4,
+            delay: 10,
+            frames: Vec::new(),
+        }
+    }
+
+    // pixels per cell along each side
+    pub fn scale(mut self, scale: usize) -> Recorder {
+        self.scale = scale.max(1);
+        self
+    }
+
+    // time each frame is shown in a GIF, in hundredths of a second
+    pub fn delay(mut self, delay: u16) -> Recorder {
+        self.delay = delay;
+        self
+    }
+
+    pub fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    // a path ending in .gif gets an animated GIF, anything else is a directory
+    // of numbered PNGs
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        if path.extension().is_some_and(|ext| ext == "gif") {
+            self.write_gif(path)
+        } else {
+            self.write_pngs(path)
+        }
+    }
+
+    pub fn write_gif(&self, path: &Path) -> Result<(), Error> {
+        let (width, height, images) = self.images()?;
+        let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(Error::TooLarge(width, height));
+        };
+
+        let out = BufWriter::new(File::create(path).map_err(Error::IO)?);
+        let mut encoder = gif::Encoder::new(out, gif_width, gif_height, &self.palette.concat())
+            .map_err(Error::Gif)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(Error::Gif)?;
+
+        for image in images {
+            let frame = gif::Frame {
+                width: gif_width,
+                height: gif_height,
+                delay: self.delay,
+                buffer: Cow::Owned(image),
+                ..Default::default()
+            };
+            encoder.write_frame(&frame).map_err(Error::Gif)?;
+        }
+        Ok(())
+    }
+
+    // frame0000.png, frame0001.png, ... in `dir`, which is created if needed
+    pub fn write_pngs(&self, dir: &Path) -> Result<(), Error> {
+        let (width, height, images) = self.images()?;
+        fs::create_dir_all(dir).map_err(Error::IO)?;
+
+        for (idx, image) in images.iter().enumerate() {
+            let path = dir.join(format!("frame{:04}.png", idx));
+            let out = BufWriter::new(File::create(path).map_err(Error::IO)?);
+
+            let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+            encoder.set_color(png::ColorType::Indexed);
+            encoder.set_depth(png::BitDepth::Eight);
+            encoder.set_palette(self.palette.concat());
+            let mut writer = encoder.write_header().map_err(Error::Png)?;
+            writer.write_image_data(image).map_err(Error::Png)?;
+        }
+        Ok(())
+    }
+
+    // every frame scaled up and padded to the size of the largest
+    fn images(&self) -> Result<(usize, usize, Vec<Vec<u8>>), Error> {
+        let width = self.frames.iter().map(Frame::width).max().unwrap_or(0);
+        let height = self.frames.iter().map(Frame::height).max().unwrap_or(0);
+        if width == 0 || height == 0 {
+            return Err(Error::Empty);
+        }
+
+        let (scaled_width, scaled_height) = (width * self.scale, height * self.scale);
+        let images = self
+            .frames
+            .iter()
+            .map(|frame| {
+                let mut image = vec![0; scaled_width * scaled_height];
+                for y in 0..frame.height * self.scale {
+                    for x in 0..frame.width * self.scale {
+                        image[y * scaled_width + x] = frame.get(x / self.scale, y / self.scale);
+                    }
+                }
+                image
+            })
+            .collect();
+
+        Ok((scaled_width, scaled_height, images))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Error, Frame, Recorder, Slices};
+    use crate::{Grid, Source};
+    use std::fs;
+
+    #[test]
+    fn test_frame_from_grid() {
+        let grid = Grid::parse(Source::text("#.\n.#\n"), |c| Some(c == '#')).unwrap();
+        let frame = Frame::grid(&grid, |&lit| lit as u8);
+        assert_eq!((frame.width(), frame.height()), (2, 2));
+        assert_eq!((frame.get(0, 0), frame.get(1, 0)), (1, 0));
+    }
+
+    #[test]
+    fn test_slices() {
+        // two z-slices, one w-slice, drawn side by side with a gap
+        let mut slices = Slices::<4>::new();
+        slices.push(vec![[0, 0, 0, 0]]);
+        slices.push(vec![[1, 1, 1, 0], [0, 0, 0, 0]]);
+
+        let frames = slices.frames(1, 2);
+        assert_eq!(frames.len(), 2);
+        assert_eq!((frames[0].width(), frames[0].height()), (5, 2));
+
+        let rows = |frame: &Frame| -> Vec<String> {
+            (0..frame.height())
+                .map(|y| {
+                    (0..frame.width())
+                        .map(|x| frame.get(x, y).to_string())
+                        .collect()
+                })
+                .collect()
+        };
+        assert_eq!(rows(&frames[0]), vec!["21011", "11011"]);
+        assert_eq!(rows(&frames[1]), vec!["21011", "11012"]);
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = std::env::temp_dir().join(format!("aoc-render-test-{}", std::process::id()));
+        let mut recorder = Recorder::new(&[[0, 0, 0], [255, 255, 255]]).scale(2);
+        assert!(matches!(
+            recorder.write(&dir.join("empty.gif")),
+            Err(Error::Empty)
+        ));
+
+        let mut frame = Frame::new(3, 2);
+        recorder.push(frame.clone());
+        frame.set(1, 1, 1);
+        recorder.push(frame);
+
+        fs::create_dir_all(&dir).unwrap();
+        recorder.write(&dir.join("out.gif")).unwrap();
+        let gif = fs::read(dir.join("out.gif")).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!((gif[6], gif[8]), (6, 4));
+
+        recorder.write(&dir.join("frames")).unwrap();
+        for name in ["frame0000.png", "frame0001.png"] {
+            let png = fs::read(dir.join("frames").join(name)).unwrap();
+            assert_eq!(&png[1..4], b"PNG");
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use crate::input::read_to_string;
+use crate::{Diagnostic, Error, Source};
+use std::ops::Index;
+
+// blank-line separated sections of a puzzle input. Lines may end in LF or
+// CRLF, trailing whitespace is dropped and runs of blank lines count as a
+// single separator.
+#[derive(Debug)]
+pub struct Sections {
+    path: String,
+    sections: Vec<Vec<String>>,
+    // 1-based line number of the first line of each section
+    starts: Vec<usize>,
+}
+
+impl Sections {
+    pub fn parse(contents: &str) -> Sections {
+        let mut sections: Vec<Vec<String>> = Vec::new();
+        let mut starts: Vec<usize> = Vec::new();
+        let mut section: Vec<String> = Vec::new();
+
+        for (idx, line) in contents.lines().map(str::trim_end).enumerate() {
+            if line.is_empty() {
+                if !section.is_empty() {
+                    sections.push(section);
+                    section = Vec::new();
+                }
+            } else {
+                if section.is_empty() {
+                    starts.push(idx + 1);
+                }
+                section.push(line.to_string());
+            }
+        }
+
+        if !section.is_empty() {
+            sections.push(section);
+        }
+
+        Sections {
+            path: String::new(),
+            sections,
+            starts,
+        }
+    }
+
+    pub fn read(source: Source) -> Result<Sections, Error> {
+        let path = source.name().to_string();
+        let mut sections = Sections::parse(&read_to_string(source)?);
+        sections.path = path;
+
+        Ok(sections)
+    }
+
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Vec<String>> {
+        self.sections.iter()
+    }
+
+    // fails unless exactly `count` sections were found
+    pub fn expect(self, count: usize) -> Result<Sections, Error> {
+        if self.len() != count {
+            return Err(Error::SectionCount {
+                expected: count,
+                found: self.len(),
+            });
+        }
+
+        Ok(self)
+    }
+
+    // 1-based line number of a line within a section
+    pub fn line_number(&self, section: usize, line: usize) -> usize {
+        self.starts[section] + line
+    }
+
+    // points at `token` within a line of a section, see Diagnostic::new
+    pub fn diagnostic(
+        &self,
+        section: usize,
+        line: usize,
+        token: &str,
+        message: impl Into<String>,
+    ) -> Diagnostic {
+        Diagnostic::new(
+            &self.path,
+            self.line_number(section, line),
+            &self.sections[section][line],
+            token,
+            message,
+        )
+    }
+
+    pub fn into_vec(self) -> Vec<Vec<String>> {
+        self.sections
+    }
+}
+
+impl Index<usize> for Sections {
+    type Output = Vec<String>;
+
+    fn index(&self, section: usize) -> &Vec<String> {
+        &self.sections[section]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Sections;
+    use crate::Error;
+
+    #[test]
+    fn test_line_endings() {
+        let lf = Sections::parse("abc\n\na\nb\n\n\nab\nac\n");
+        let crlf = Sections::parse("abc\r\n\r\na\r\nb\r\n\r\n\r\nab\r\nac\r\n");
+        let padded = Sections::parse("\n  \nabc  \n \t\na\t\nb\n\n\nab\nac");
+
+        assert_eq!(lf.len(), 3);
+        assert!(lf.iter().eq(crlf.iter()));
+        assert!(lf.iter().eq(padded.iter()));
+        assert_eq!((lf.line_number(2, 1), padded.line_number(2, 1)), (8, 10));
+        assert_eq!(
+            lf.into_vec(),
+            vec![vec!["abc"], vec!["a", "b"], vec!["ab", "ac"]]
+        );
+    }
+
+    #[test]
+    fn test_expect() {
+        let sections = Sections::parse("6,10\n0,14\n\nfold along y=7\n");
+        let sections = sections.expect(2).unwrap();
+        assert_eq!(sections[1], vec!["fold along y=7"]);
+
+        let diagnostic = sections.diagnostic(1, 0, "y=7", "unknown axis");
+        assert_eq!(
+            (diagnostic.line, diagnostic.column, diagnostic.width),
+            (4, 12, 3)
+        );
+
+        match Sections::parse("a\n\nb\n\nc").expect(2) {
+            Err(Error::SectionCount { expected, found }) => assert_eq!((expected, found), (2, 3)),
+            res => panic!("unexpected {:?}", res),
+        }
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(Sections::parse("").is_empty());
+        assert!(Sections::parse("\r\n\r\n").is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{InputCache, Source};
+use std::fmt::{Debug, Display};
+
+pub trait Solution {
+    type Input;
+    type Config: Default;
+    type PartOne: Display;
+    type PartTwo: Display;
+    type Error: Debug;
+
+    fn parse(source: Source) -> Result<Self::Input, Self::Error>;
+
+    fn part_one(input: &Self::Input, config: &Self::Config) -> Result<Self::PartOne, Self::Error>;
+
+    fn part_two(input: &Self::Input, config: &Self::Config) -> Result<Self::PartTwo, Self::Error>;
+}
+
+// parses the input once and prints both parts using the default config
+pub fn run<S: Solution>(source: Source) {
+    let input = match S::parse(source) {
+        Err(e) => return println!("Parse error: {:?}", e),
+        Ok(input) => input,
+    };
+    let config = S::Config::default();
+
+    match S::part_one(&input, &config) {
+        Err(e) => println!("Run part one error: {:?}", e),
+        Ok(answer) => println!("Run part one: {}", answer),
+    }
+
+    match S::part_two(&input, &config) {
+        Err(e) => println!("Run part two error: {:?}", e),
+        Ok(answer) => println!("Run part two: {}", answer),
+    }
+}
+
+// runs a day on its cached puzzle input, see InputCache::from_env
+pub fn run_day<S: Solution>(year: u32, day: u32) {
+    match InputCache::from_env().get(year, day) {
+        Err(e) => println!("Input error: {}", e),
+        Ok(path) => run::<S>(Source::file(path)),
+    }
+}
@@ -0,0 +1,117 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Cursor, Read};
+use std::path::PathBuf;
+
+// where a puzzle input comes from: a file, stdin, inline text or any reader.
+// Files are opened on first read, so a missing file surfaces as an io::Error
+// from the reader like any other read failure.
+pub struct Source {
+    name: String,
+    reader: Reader,
+}
+
+enum Reader {
+    Unopened(PathBuf),
+    Open(Box<dyn BufRead>),
+}
+
+impl Source {
+    pub fn file(path: impl Into<PathBuf>) -> Source {
+        let path = path.into();
+        Source {
+            name: path.to_string_lossy().into_owned(),
+            reader: Reader::Unopened(path),
+        }
+    }
+
+    pub fn stdin() -> Source {
+        Source::reader("<stdin>", io::stdin().lock())
+    }
+
+    pub fn text(text: impl Into<String>) -> Source {
+        Source::reader("<input>", Cursor::new(text.into()))
+    }
+
+    pub fn reader(name: &str, reader: impl BufRead + 'static) -> Source {
+        Source {
+            name: name.to_string(),
+            reader: Reader::Open(Box::new(reader)),
+        }
+    }
+
+    // replaces the name used in diagnostics
+    pub fn named(mut self, name: &str) -> Source {
+        self.name = name.to_string();
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn open(&mut self) -> io::Result<&mut dyn BufRead> {
+        if let Reader::Unopened(path) = &self.reader {
+            let file = File::open(path)?;
+            self.reader = Reader::Open(Box::new(BufReader::new(file)));
+        }
+
+        match &mut self.reader {
+            Reader::Open(reader) => Ok(reader.as_mut()),
+            Reader::Unopened(_) => unreachable!(),
+        }
+    }
+}
+
+impl Read for Source {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.open()?.read(buf)
+    }
+}
+
+impl BufRead for Source {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.open()?.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if let Reader::Open(reader) = &mut self.reader {
+            reader.consume(amt);
+        }
+    }
+}
+
+impl fmt::Debug for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Source({:?})", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Source;
+    use std::io::{BufRead, Read};
+
+    #[test]
+    fn test_text() {
+        let source = Source::text("1\n2\n3\n");
+        assert_eq!(source.name(), "<input>");
+
+        let lines: Vec<String> = source.lines().map(Result::unwrap).collect();
+        assert_eq!(lines, vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_file() {
+        let mut contents = String::new();
+        let mut source = Source::file("ints_test.txt");
+        assert_eq!(source.name(), "ints_test.txt");
+
+        source.read_to_string(&mut contents).unwrap();
+        assert_eq!(contents, "1721\n979\n-366\n");
+
+        let mut missing = Source::file("missing_test.txt").named("example");
+        assert_eq!(missing.name(), "example");
+        assert!(missing.read_to_string(&mut contents).is_err());
+    }
+}
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+// recorded answers for one day, as printed by the runner
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+
+pub struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            _ => self.part2.as_deref(),
+        }
+    }
+
+    fn set(&mut self, part: u8, answer: String) {
+        match part {
+            1 => self.part1 = Some(answer),
+            _ => self.part2 = Some(answer),
+        }
+    }
+}
+
+// answers keyed by year and day, stored as `[<year>.<day>]` tables
+#[derive(Debug, Default, PartialEq)]
+pub struct Answers(BTreeMap<u32, BTreeMap<u32, DayAnswers>>);
+
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+    Key(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{}", e),
+            Error::Parse(e) => write!(f, "{}", e),
+            Error::Serialize(e) => write!(f, "{}", e),
+            Error::Key(key) => write!(f, "expected a year or day number, found {:?}", key),
+        }
+    }
+}
+
+impl Answers {
+    pub fn get(&self, year: u32, day: u32, part: u8) -> Option<&str> {
+        self.0.get(&year)?.get(&day)?.get(part)
+    }
+
+    pub fn set(&mut self, year: u32, day: u32, part: u8, answer: String) {
+        self.0
+            .entry(year)
+            .or_default()
+            .entry(day)
+            .or_default()
+            .set(part, answer);
+    }
+
+    // an empty entry for a day, so it shows up in the file before it is solved
+    pub fn add_day(&mut self, year: u32, day: u32) {
+        self.0.entry(year).or_default().entry(day).or_default();
+    }
+
+    pub fn parse(contents: &str) -> Result<Answers, Error> {
+        let tables: BTreeMap<String, BTreeMap<String, DayAnswers>> =
+            toml::from_str(contents).map_err(Error::Parse)?;
+        let number = |key: &str| key.parse::<u32>().map_err(|_| Error::Key(key.to_string()));
+
+        let mut answers = Answers::default();
+        for (year, days) in tables {
+            let year = number(&year)?;
+            for (day, day_answers) in days {
+                answers
+                    .0
+                    .entry(year)
+                    .or_default()
+                    .insert(number(&day)?, day_answers);
+            }
+        }
+
+        Ok(answers)
+    }
+
+    // a missing file has no recorded answers yet
+    pub fn read(path: &Path) -> Result<Answers, Error> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(Error::IO(e)),
+        }
+    }
+
+    pub fn to_toml(&self) -> Result<String, Error> {
+        toml::to_string(self).map_err(Error::Serialize)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), Error> {
+        fs::write(path, self.to_toml()?).map_err(Error::IO)
+    }
+}
+
+// toml only allows string keys, so write the numbers as strings in numeric order
+impl Serialize for Answers {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.0
+                .iter()
+                .map(|(year, days)| (year.to_string(), Days(days))),
+        )
+    }
+}
+
+struct Days<'a>(&'a BTreeMap<u32, DayAnswers>);
+
+impl Serialize for Days<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(
+            self.0
+                .iter()
+                .map(|(day, answers)| (day.to_string(), answers)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+
+    #[test]
+    fn test_round_trip() {
+        let mut answers = Answers::default();
+        answers.set(2020, 10, 1, "2516".to_string());
+        answers.set(2020, 2, 1, "582".to_string());
+        answers.set(2020, 2, 2, "729".to_string());
+        answers.set(2021, 13, 2, "\n#..\n.#.".to_string());
+
+        let toml = answers.to_toml().unwrap();
+        assert!(toml.find("[2020.2]").unwrap() < toml.find("[2020.10]").unwrap());
+
+        let reparsed = Answers::parse(&toml).unwrap();
+        assert_eq!(reparsed, answers);
+        assert_eq!(reparsed.get(2020, 2, 2), Some("729"));
+        assert_eq!(reparsed.get(2020, 10, 2), None);
+        assert_eq!(reparsed.get(2021, 13, 2), Some("\n#..\n.#."));
+
+        answers.add_day(2022, 1);
+        let toml = answers.to_toml().unwrap();
+        assert!(toml.contains("[2022.1]"));
+        assert_eq!(Answers::parse(&toml).unwrap(), answers);
+    }
+
+    #[test]
+    fn test_bad_key() {
+        assert!(Answers::parse("[2020.first]\npart1 = \"1\"\n").is_err());
+    }
+}
@@ -0,0 +1,307 @@
+use crate::registry::Day;
+use aoc_common::Source;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Phase {
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::PartOne => "part1",
+            Phase::PartTwo => "part2",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Phase> {
+        match name {
+            "parse" => Some(Phase::Parse),
+            "part1" => Some(Phase::PartOne),
+            "part2" => Some(Phase::PartTwo),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    // the median of an even number of samples is the mean of the middle two
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let len = sorted.len();
+        let median = match len {
+            0 => return None,
+            _ if len % 2 == 1 => sorted[len / 2],
+            _ => (sorted[len / 2 - 1] + sorted[len / 2]) / 2,
+        };
+
+        Some(Stats {
+            min: sorted[0],
+            median,
+            max: sorted[len - 1],
+        })
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min {:>10} median {:>10} max {:>10}",
+            format!("{:.2?}", self.min),
+            format!("{:.2?}", self.median),
+            format!("{:.2?}", self.max),
+        )
+    }
+}
+
+pub struct Measurement {
+    pub year: u32,
+    pub day: u32,
+    pub phase: Phase,
+    pub result: Result<Stats, String>,
+}
+
+// times parsing and both parts of a day `runs` times each, parsing a fresh
+// source from `input` every run
+pub fn measure(day: &Day, input: impl Fn() -> Source, runs: usize) -> Vec<Measurement> {
+    let measurement = |phase, result| Measurement {
+        year: day.year,
+        day: day.day,
+        phase,
+        result,
+    };
+
+    let mut samples = Vec::with_capacity(runs);
+    let mut parsed = None;
+    for _ in 0..runs {
+        let source = input();
+        let start = Instant::now();
+        let loaded = (day.load)(source);
+        samples.push(start.elapsed());
+
+        match loaded {
+            Ok(loaded) => parsed = Some(loaded),
+            Err(e) => return vec![measurement(Phase::Parse, Err(e))],
+        }
+    }
+
+    let parsed = match parsed {
+        Some(parsed) => parsed,
+        None => return Vec::new(),
+    };
+
+    let mut measurements = vec![measurement(
+        Phase::Parse,
+        Stats::from_samples(&samples).ok_or_else(String::new),
+    )];
+
+    for phase in [Phase::PartOne, Phase::PartTwo] {
+        samples.clear();
+        let mut error = None;
+        for _ in 0..runs {
+            let start = Instant::now();
+            let answer = match phase {
+                Phase::PartOne => parsed.part_one(),
+                _ => parsed.part_two(),
+            };
+            samples.push(start.elapsed());
+
+            if let Err(e) = answer {
+                error = Some(e);
+                break;
+            }
+        }
+
+        let result = match error {
+            Some(e) => Err(e),
+            None => Stats::from_samples(&samples).ok_or_else(String::new),
+        };
+        measurements.push(measurement(phase, result));
+    }
+
+    measurements
+}
+
+// median timings from a previous run, keyed by year, day and phase
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline(HashMap<(u32, u32, Phase), Duration>);
+
+#[derive(Debug)]
+pub enum BaselineError {
+    IO(io::Error),
+    Parse(usize, String),
+}
+
+impl fmt::Display for BaselineError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BaselineError::IO(e) => write!(f, "{}", e),
+            BaselineError::Parse(line, text) => write!(f, "line {}: cannot parse {:?}", line, text),
+        }
+    }
+}
+
+impl Baseline {
+    pub fn from_measurements(measurements: &[Measurement]) -> Baseline {
+        Baseline(
+            measurements
+                .iter()
+                .filter_map(|m| {
+                    let stats = m.result.as_ref().ok()?;
+                    Some(((m.year, m.day, m.phase), stats.median))
+                })
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, year: u32, day: u32, phase: Phase) -> Option<Duration> {
+        self.0.get(&(year, day, phase)).copied()
+    }
+
+    // one "<year> <day> <phase> <median nanoseconds>" entry per line
+    pub fn parse(contents: &str) -> Result<Baseline, BaselineError> {
+        let mut entries = HashMap::new();
+
+        for (idx, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let error = || BaselineError::Parse(idx + 1, line.to_string());
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [year, day, phase, nanos] = fields[..] else {
+                return Err(error());
+            };
+
+            entries.insert(
+                (
+                    year.parse().map_err(|_| error())?,
+                    day.parse().map_err(|_| error())?,
+                    Phase::from_name(phase).ok_or_else(error)?,
+                ),
+                Duration::from_nanos(nanos.parse().map_err(|_| error())?),
+            );
+        }
+
+        Ok(Baseline(entries))
+    }
+
+    pub fn read(path: &Path) -> Result<Baseline, BaselineError> {
+        Baseline::parse(&fs::read_to_string(path).map_err(BaselineError::IO)?)
+    }
+
+    pub fn write(&self, path: &Path) -> Result<(), BaselineError> {
+        fs::write(path, self.to_string()).map_err(BaselineError::IO)
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keys: Vec<&(u32, u32, Phase)> = self.0.keys().collect();
+        keys.sort_by_key(|&&(year, day, phase)| (year, day, phase as u8));
+
+        writeln!(f, "# year day phase median_ns")?;
+        for key in keys {
+            let (year, day, phase) = *key;
+            writeln!(
+                f,
+                "{} {} {} {}",
+                year,
+                day,
+                phase.name(),
+                self.0[key].as_nanos()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+// relative change of the median against the baseline, e.g. 0.25 for 25% slower
+pub fn change(stats: &Stats, baseline: Duration) -> Option<f64> {
+    if baseline.is_zero() {
+        return None;
+    }
+
+    Some(stats.median.as_secs_f64() / baseline.as_secs_f64() - 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{change, Baseline, Measurement, Phase, Stats};
+    use std::time::Duration;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(2), ms(8)]).unwrap();
+        assert_eq!(stats.median, ms(3));
+
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let stats = Stats::from_samples(&[ms(2)]).unwrap();
+        let measurements = vec![
+            Measurement {
+                year: 2020,
+                day: 15,
+                phase: Phase::PartTwo,
+                result: Ok(stats),
+            },
+            Measurement {
+                year: 2021,
+                day: 1,
+                phase: Phase::Parse,
+                result: Err("failed".to_string()),
+            },
+        ];
+
+        let baseline = Baseline::from_measurements(&measurements);
+        assert_eq!(baseline.get(2020, 15, Phase::PartTwo), Some(ms(2)));
+        assert_eq!(baseline.get(2021, 1, Phase::Parse), None);
+
+        let reparsed = Baseline::parse(&baseline.to_string()).unwrap();
+        assert_eq!(reparsed, baseline);
+
+        assert!(Baseline::parse("2020 15 part3 100").is_err());
+        assert!(Baseline::parse("2020 15 parse").is_err());
+    }
+
+    #[test]
+    fn test_change() {
+        let stats = Stats::from_samples(&[ms(15)]).unwrap();
+        assert!((change(&stats, ms(10)).unwrap() - 0.5).abs() < 1e-9);
+
+        assert_eq!(change(&stats, Duration::ZERO), None);
+    }
+}
@@ -0,0 +1,642 @@
+mod answers;
+mod bench;
+#[cfg(feature = "render")]
+mod record;
+mod registry;
+mod report;
+mod scaffold;
+mod summary;
+
+use answers::Answers;
+use aoc_common::{InputCache, Source};
+use bench::{Baseline, Measurement};
+use clap::{Args, Parser, Subcommand};
+use rayon::prelude::*;
+use registry::{Day, DAYS};
+use report::{Format, Record, Report};
+use std::fs;
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Instant;
+use summary::Summary;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a single day, every day of a year, or every day
+    Run(RunArgs),
+    /// List every registered day and its cached input
+    List,
+    /// Time parsing and both parts of a day, or every day of a year
+    Bench(BenchArgs),
+    /// Check every day's answers against the recorded answers
+    Verify(VerifyArgs),
+    /// Generate a new day from the template and register it
+    New(NewArgs),
+    /// Run every day of a year, or every day, in parallel and summarise the results
+    All(AllArgs),
+    /// Write a day's simulation out as an animated GIF or numbered PNGs
+    #[cfg(feature = "render")]
+    Record(record::RecordArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: Option<u32>,
+    #[arg(long, requires = "year")]
+    day: Option<u32>,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Input file to use instead of the day's cached input, or - for stdin
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Input text to use instead of the day's cached input, e.g. an example
+    #[arg(long, requires = "day", conflicts_with = "input")]
+    input_text: Option<String>,
+    /// Output format, json and csv give one record per part for scripts
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[arg(long)]
+    year: u32,
+    #[arg(long)]
+    day: Option<u32>,
+    /// Input file to use instead of the day's cached input, or - for stdin
+    #[arg(long, requires = "day")]
+    input: Option<PathBuf>,
+    /// Input text to use instead of the day's cached input, e.g. an example
+    #[arg(long, requires = "day", conflicts_with = "input")]
+    input_text: Option<String>,
+    /// Number of times each phase is run
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    runs: u32,
+    /// Write the median timings to this file for later comparison
+    #[arg(long)]
+    save: Option<PathBuf>,
+    /// Compare the median timings against a file written by --save
+    #[arg(long)]
+    baseline: Option<PathBuf>,
+    /// Percentage slowdown against the baseline reported as a regression
+    #[arg(long, default_value_t = 10.0, requires = "baseline")]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    #[arg(long)]
+    year: u32,
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=25))]
+    day: u32,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[arg(long)]
+    year: Option<u32>,
+    #[arg(long, requires = "year")]
+    day: Option<u32>,
+    /// Answers file to check against, defaults to answers.toml in the repository root
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Store the current answers instead of checking them
+    #[arg(long)]
+    record: bool,
+}
+
+#[derive(Args)]
+struct AllArgs {
+    #[arg(long)]
+    year: Option<u32>,
+    /// Answers file to check against, defaults to answers.toml in the repository root
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// Number of days run at once, defaults to the number of cpus
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run(args) => run(&args),
+        Command::List => list(),
+        Command::Bench(args) => bench(&args),
+        Command::Verify(args) => verify(&args),
+        Command::New(args) => new(&args),
+        Command::All(args) => all(&args),
+        #[cfg(feature = "render")]
+        Command::Record(args) => record::run(&args),
+    }
+}
+
+fn select(year: Option<u32>, day: Option<u32>) -> Option<Vec<&'static Day>> {
+    let days: Vec<&Day> = DAYS
+        .iter()
+        .filter(|d| year.is_none_or(|year| d.year == year) && day.is_none_or(|day| d.day == day))
+        .collect();
+
+    if days.is_empty() {
+        match (year, day) {
+            (Some(year), Some(day)) => eprintln!("{} day {} is not implemented", year, day),
+            (Some(year), None) => eprintln!("no days implemented for {}", year),
+            _ => eprintln!("no days implemented"),
+        }
+        return None;
+    }
+
+    Some(days)
+}
+
+fn root() -> PathBuf {
+    match Path::new(env!("CARGO_MANIFEST_DIR")).parent() {
+        Some(root) => root.to_path_buf(),
+        None => PathBuf::from("."),
+    }
+}
+
+fn answers_path(answers: &Option<PathBuf>) -> PathBuf {
+    match answers {
+        Some(path) => path.clone(),
+        None => root().join("answers.toml"),
+    }
+}
+
+// a day's puzzle input; stdin is read up front so it can be parsed more than once
+#[derive(Clone)]
+enum Input {
+    File(PathBuf),
+    Text(&'static str, String),
+}
+
+impl Input {
+    // --input-text, or --input where - means stdin
+    fn from_args(
+        input: &Option<PathBuf>,
+        input_text: &Option<String>,
+    ) -> io::Result<Option<Input>> {
+        if let Some(text) = input_text {
+            return Ok(Some(Input::Text("<input>", text.clone())));
+        }
+
+        match input {
+            Some(path) if path.as_os_str() == "-" => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text)?;
+                Ok(Some(Input::Text("<stdin>", text)))
+            }
+            Some(path) => Ok(Some(Input::File(path.clone()))),
+            None => Ok(None),
+        }
+    }
+
+    fn source(&self) -> Source {
+        match self {
+            Input::File(path) => Source::file(path),
+            Input::Text(name, text) => Source::text(text.as_str()).named(name),
+        }
+    }
+
+    fn hash(&self) -> io::Result<String> {
+        match self {
+            Input::File(path) => Ok(report::input_hash(&fs::read(path)?)),
+            Input::Text(_, text) => Ok(report::input_hash(text.as_bytes())),
+        }
+    }
+}
+
+// the input given on the command line if any, otherwise the cached (or freshly
+// fetched) input
+fn day_input(
+    cache: &InputCache,
+    day: &Day,
+    input: &Option<Input>,
+) -> Result<Input, aoc_common::Error> {
+    match input {
+        Some(input) => Ok(input.clone()),
+        None => cache.get(day.year, day.day).map(Input::File),
+    }
+}
+
+fn list() -> ExitCode {
+    let cache = InputCache::from_env();
+
+    for day in DAYS {
+        let path = cache.path(day.year, day.day);
+        let status = if path.exists() { "" } else { " (not cached)" };
+        println!("{} day {}: {}{}", day.year, day.day, path.display(), status);
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn run(args: &RunArgs) -> ExitCode {
+    let Some(days) = select(args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let input = match Input::from_args(&args.input, &args.input_text) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("cannot read stdin: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let cache = InputCache::from_env();
+    let mut report = Report::new(args.format, io::stdout().lock());
+    let mut failed = false;
+
+    for day in days {
+        for record in run_day(&cache, day, &input, args.part) {
+            failed |= record.error.is_some();
+            if let Err(e) = report.write(&record) {
+                eprintln!("cannot write output: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    if let Err(e) = report.finish() {
+        eprintln!("cannot write output: {}", e);
+        return ExitCode::FAILURE;
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// one record per part run, or a single record without a part if the input
+// cannot be found or parsed
+fn run_day(cache: &InputCache, day: &Day, input: &Option<Input>, part: Option<u8>) -> Vec<Record> {
+    let record = |part, result: Result<String, String>, input_hash, duration_ns| {
+        let (answer, error) = match result {
+            Ok(answer) => (Some(answer), None),
+            Err(e) => (None, Some(e)),
+        };
+        Record {
+            year: day.year,
+            day: day.day,
+            part,
+            answer,
+            input_hash,
+            duration_ns,
+            error,
+        }
+    };
+
+    let input = match day_input(cache, day, input) {
+        Ok(input) => input,
+        Err(e) => return vec![record(None, Err(format!("input error: {}", e)), None, None)],
+    };
+    let input_hash = input.hash().ok();
+
+    let start = Instant::now();
+    let parsed = match (day.load)(input.source()) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            let duration_ns = Some(start.elapsed().as_nanos() as u64);
+            let result = Err(format!("parse error: {}", e));
+            return vec![record(None, result, input_hash, duration_ns)];
+        }
+    };
+
+    [1, 2]
+        .into_iter()
+        .filter(|&p| part.is_none_or(|part| part == p))
+        .map(|part| {
+            let start = Instant::now();
+            let answer = if part == 1 {
+                parsed.part_one()
+            } else {
+                parsed.part_two()
+            };
+            let duration_ns = Some(start.elapsed().as_nanos() as u64);
+            record(Some(part), answer, input_hash.clone(), duration_ns)
+        })
+        .collect()
+}
+
+fn bench(args: &BenchArgs) -> ExitCode {
+    let Some(days) = select(Some(args.year), args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let baseline = match &args.baseline {
+        Some(path) => match Baseline::read(path) {
+            Ok(baseline) => Some(baseline),
+            Err(e) => {
+                eprintln!("cannot read baseline {}: {}", path.display(), e);
+                return ExitCode::FAILURE;
+            }
+        },
+        None => None,
+    };
+
+    let input = match Input::from_args(&args.input, &args.input_text) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("cannot read stdin: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let cache = InputCache::from_env();
+    let mut measurements: Vec<Measurement> = Vec::new();
+    let mut failed = false;
+
+    for day in days {
+        let input = match day_input(&cache, day, &input) {
+            Ok(input) => input,
+            Err(e) => {
+                failed = true;
+                println!("{} day {} input error: {}", day.year, day.day, e);
+                continue;
+            }
+        };
+
+        for m in bench::measure(day, || input.source(), args.runs as usize) {
+            let stats = match &m.result {
+                Ok(stats) => stats,
+                Err(e) => {
+                    failed = true;
+                    println!(
+                        "{} day {:>2} {}  error: {}",
+                        m.year,
+                        m.day,
+                        m.phase.name(),
+                        e
+                    );
+                    continue;
+                }
+            };
+
+            let comparison = baseline
+                .as_ref()
+                .and_then(|b| b.get(m.year, m.day, m.phase))
+                .and_then(|median| bench::change(stats, median))
+                .map(|change| {
+                    let regressed = change * 100.0 > args.threshold;
+                    failed |= regressed;
+                    format!(
+                        "  {:+.1}% vs baseline{}",
+                        change * 100.0,
+                        if regressed { "  REGRESSION" } else { "" }
+                    )
+                })
+                .unwrap_or_default();
+
+            println!(
+                "{} day {:>2} {}  {}{}",
+                m.year,
+                m.day,
+                m.phase.name(),
+                stats,
+                comparison
+            );
+            measurements.push(m);
+        }
+    }
+
+    if let Some(path) = &args.save {
+        if let Err(e) = Baseline::from_measurements(&measurements).write(path) {
+            eprintln!("cannot write baseline {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(args: &VerifyArgs) -> ExitCode {
+    let Some(days) = select(args.year, args.day) else {
+        return ExitCode::FAILURE;
+    };
+
+    let path = answers_path(&args.answers);
+    let mut answers = match Answers::read(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("cannot read answers {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let cache = InputCache::from_env();
+    let (mut passed, mut failed, mut unrecorded) = (0, 0, 0);
+
+    for day in days {
+        let input = match day_input(&cache, day, &None) {
+            Ok(input) => input,
+            Err(e) => {
+                failed += 2;
+                println!("{} day {} input error: {}", day.year, day.day, e);
+                continue;
+            }
+        };
+
+        let parsed = match (day.load)(input.source()) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                failed += 2;
+                println!("{} day {} parse error: {}", day.year, day.day, e);
+                continue;
+            }
+        };
+
+        for part in [1, 2] {
+            let answer = if part == 1 {
+                parsed.part_one()
+            } else {
+                parsed.part_two()
+            };
+            let answer = match answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    failed += 1;
+                    println!("{} day {} part {} error: {}", day.year, day.day, part, e);
+                    continue;
+                }
+            };
+
+            let recorded = answers.get(day.year, day.day, part);
+            let label = format!("{} day {} part {}", day.year, day.day, part);
+            match recorded {
+                Some(expected) if expected == answer => passed += 1,
+                _ if args.record => {
+                    match recorded {
+                        Some(previous) => {
+                            println!("{}: recorded {:?} (was {:?})", label, answer, previous)
+                        }
+                        None => println!("{}: recorded {:?}", label, answer),
+                    }
+                    answers.set(day.year, day.day, part, answer);
+                }
+                Some(expected) => {
+                    failed += 1;
+                    println!("{}: expected {:?}, got {:?}", label, expected, answer);
+                }
+                None => {
+                    unrecorded += 1;
+                    println!("{}: no recorded answer, got {:?}", label, answer);
+                }
+            }
+        }
+    }
+
+    if args.record {
+        if let Err(e) = answers.write(&path) {
+            eprintln!("cannot write answers {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    } else {
+        println!(
+            "{} passed, {} failed, {} unrecorded",
+            passed, failed, unrecorded
+        );
+    }
+
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new(args: &NewArgs) -> ExitCode {
+    match scaffold::new_day(&root(), args.year, args.day) {
+        Ok(changed) => {
+            for path in changed {
+                println!("{}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("cannot create {} day {}: {}", args.year, args.day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// a panic inside a day becomes an error record for that day rather than
+// taking down the other days
+fn run_isolated(cache: &InputCache, day: &Day) -> Vec<Record> {
+    panic::catch_unwind(AssertUnwindSafe(|| run_day(cache, day, &None, None))).unwrap_or_else(
+        |payload| {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+
+            vec![Record {
+                year: day.year,
+                day: day.day,
+                part: None,
+                answer: None,
+                input_hash: None,
+                duration_ns: None,
+                error: Some(format!("panicked: {}", message)),
+            }]
+        },
+    )
+}
+
+fn all(args: &AllArgs) -> ExitCode {
+    let Some(days) = select(args.year, None) else {
+        return ExitCode::FAILURE;
+    };
+
+    let path = answers_path(&args.answers);
+    let answers = match Answers::read(&path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("cannot read answers {}: {}", path.display(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut pool = rayon::ThreadPoolBuilder::new();
+    if let Some(jobs) = args.jobs {
+        pool = pool.num_threads(jobs as usize);
+    }
+    let pool = match pool.build() {
+        Ok(pool) => pool,
+        Err(e) => {
+            eprintln!("cannot start threads: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let cache = InputCache::from_env();
+    let start = Instant::now();
+    let records: Vec<Vec<Record>> = pool.install(|| {
+        days.par_iter()
+            .map(|day| run_isolated(&cache, day))
+            .collect()
+    });
+    let elapsed = start.elapsed();
+
+    let mut summary = Summary::default();
+    for record in records.into_iter().flatten() {
+        summary.add(record, &answers);
+    }
+
+    println!("{}", summary);
+    println!(
+        "ran {} days with {} jobs in {:.2?}",
+        days.len(),
+        pool.current_num_threads(),
+        elapsed
+    );
+
+    if summary.failed() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{run_isolated, Day};
+    use aoc_common::InputCache;
+    use std::fs;
+
+    #[test]
+    fn test_panic_is_isolated() {
+        let dir = std::env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2099")).unwrap();
+        fs::write(dir.join("2099").join("day01.txt"), "1\n").unwrap();
+
+        let day = Day {
+            year: 2099,
+            day: 1,
+            load: |_| panic!("bad day"),
+        };
+        let records = run_isolated(&InputCache::new(&dir), &day);
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].error.as_deref(), Some("panicked: bad day"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
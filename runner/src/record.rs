@@ -0,0 +1,110 @@
+use crate::{day_input, select, Input};
+use aoc_common::render::{Animated, Recorder};
+use aoc_common::{InputCache, Source};
+use clap::Args;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// a day whose simulation can be written out as an animation
+pub struct Animation {
+    pub year: u32,
+    pub day: u32,
+    pub record: fn(Source, u8) -> Result<Recorder, String>,
+}
+
+fn record<S: Animated>(source: Source, part: u8) -> Result<Recorder, String> {
+    let input = S::parse(source).map_err(|e| format!("{:?}", e))?;
+    Ok(S::record(&input, part))
+}
+
+const fn animation<S: Animated + 'static>(year: u32, day: u32) -> Animation {
+    Animation {
+        year,
+        day,
+        record: record::<S>,
+    }
+}
+
+pub const ANIMATIONS: &[Animation] = &[
+    animation::<aoc2020_day11::Day11>(2020, 11),
+    animation::<aoc2020_day17::Day17>(2020, 17),
+    animation::<aoc2021_day11::Day11>(2021, 11),
+];
+
+#[derive(Args)]
+pub struct RecordArgs {
+    #[arg(long)]
+    year: u32,
+    #[arg(long)]
+    day: u32,
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// Input file to use instead of the day's cached input, or - for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Input text to use instead of the day's cached input, e.g. an example
+    #[arg(long, conflicts_with = "input")]
+    input_text: Option<String>,
+    /// A .gif file for an animation, otherwise a directory for numbered PNGs
+    #[arg(long)]
+    out: PathBuf,
+    /// Pixels per cell along each side, instead of the day's own choice
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..))]
+    scale: Option<u32>,
+}
+
+pub fn run(args: &RecordArgs) -> ExitCode {
+    let Some(animation) = ANIMATIONS
+        .iter()
+        .find(|a| a.year == args.year && a.day == args.day)
+    else {
+        eprintln!(
+            "{} day {} cannot be recorded, try one of:",
+            args.year, args.day
+        );
+        for a in ANIMATIONS {
+            eprintln!("  --year {} --day {}", a.year, a.day);
+        }
+        return ExitCode::FAILURE;
+    };
+    let Some(days) = select(Some(args.year), Some(args.day)) else {
+        return ExitCode::FAILURE;
+    };
+
+    let input = match Input::from_args(&args.input, &args.input_text) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("cannot read stdin: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let input = match day_input(&InputCache::from_env(), days[0], &input) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("{} day {} input error: {}", args.year, args.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut recorder = match (animation.record)(input.source(), args.part) {
+        Ok(recorder) => recorder,
+        Err(e) => {
+            eprintln!("{} day {} parse error: {}", args.year, args.day, e);
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(scale) = args.scale {
+        recorder = recorder.scale(scale as usize);
+    }
+
+    match recorder.write(&args.out) {
+        Ok(()) => {
+            println!("wrote {} frames to {}", recorder.len(), args.out.display());
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("cannot write {}: {}", args.out.display(), e);
+            ExitCode::FAILURE
+        }
+    }
+}
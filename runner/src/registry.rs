@@ -0,0 +1,88 @@
+use aoc_common::{Solution, Source};
+
+pub struct Day {
+    pub year: u32,
+    pub day: u32,
+    pub load: fn(Source) -> Result<Box<dyn Parsed>, String>,
+}
+
+// a day's parsed input together with its default config
+pub trait Parsed {
+    fn part_one(&self) -> Result<String, String>;
+    fn part_two(&self) -> Result<String, String>;
+}
+
+struct Loaded<S: Solution> {
+    input: S::Input,
+    config: S::Config,
+}
+
+impl<S: Solution> Parsed for Loaded<S> {
+    fn part_one(&self) -> Result<String, String> {
+        S::part_one(&self.input, &self.config)
+            .map(|answer| answer.to_string())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn part_two(&self) -> Result<String, String> {
+        S::part_two(&self.input, &self.config)
+            .map(|answer| answer.to_string())
+            .map_err(|e| format!("{:?}", e))
+    }
+}
+
+fn load<S: Solution + 'static>(source: Source) -> Result<Box<dyn Parsed>, String> {
+    let input = S::parse(source).map_err(|e| format!("{:?}", e))?;
+
+    Ok(Box::new(Loaded::<S> {
+        input,
+        config: S::Config::default(),
+    }))
+}
+
+const fn entry<S: Solution + 'static>(year: u32, day: u32) -> Day {
+    Day {
+        year,
+        day,
+        load: load::<S>,
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    entry::<aoc2019::day01::Day01>(2019, 1),
+    entry::<aoc2019::day02::Day02>(2019, 2),
+    entry::<aoc2019::day03::Day03>(2019, 3),
+    entry::<aoc2019::day04::Day04>(2019, 4),
+    entry::<aoc2020_day1::Day1>(2020, 1),
+    entry::<aoc2020_day2::Day2>(2020, 2),
+    entry::<aoc2020_day3::Day3>(2020, 3),
+    entry::<aoc2020_day4::Day4>(2020, 4),
+    entry::<aoc2020_day5::Day5>(2020, 5),
+    entry::<aoc2020_day6::Day6>(2020, 6),
+    entry::<aoc2020_day7::Day7>(2020, 7),
+    entry::<aoc2020_day8::Day8>(2020, 8),
+    entry::<aoc2020_day9::Day9>(2020, 9),
+    entry::<aoc2020_day10::Day10>(2020, 10),
+    entry::<aoc2020_day11::Day11>(2020, 11),
+    entry::<aoc2020_day12::Day12>(2020, 12),
+    entry::<aoc2020_day13::Day13>(2020, 13),
+    entry::<aoc2020_day14::Day14>(2020, 14),
+    entry::<aoc2020_day15::Day15>(2020, 15),
+    entry::<aoc2020_day16::Day16>(2020, 16),
+    entry::<aoc2020_day17::Day17>(2020, 17),
+    entry::<aoc2020_day18::Day18>(2020, 18),
+    entry::<aoc2021_day1::Day1>(2021, 1),
+    entry::<aoc2021_day2::Day2>(2021, 2),
+    entry::<aoc2021_day3::Day3>(2021, 3),
+    entry::<aoc2021_day4::Day4>(2021, 4),
+    entry::<aoc2021_day5::Day5>(2021, 5),
+    entry::<aoc2021_day6::Day6>(2021, 6),
+    entry::<aoc2021_day7::Day7>(2021, 7),
+    entry::<aoc2021_day8::Day8>(2021, 8),
+    entry::<aoc2021_day9::Day9>(2021, 9),
+    entry::<aoc2021_day10::Day10>(2021, 10),
+    entry::<aoc2021_day11::Day11>(2021, 11),
+    entry::<aoc2021_day12::Day12>(2021, 12),
+    entry::<aoc2021_day13::Day13>(2021, 13),
+    entry::<aoc2021_day14::Day14>(2021, 14),
+];
@@ -0,0 +1,186 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::io::{self, Write};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// One line per answer, for people
+    Text,
+    /// One JSON object per line
+    Json,
+    /// Comma separated with a header row
+    Csv,
+}
+
+// the outcome of one part of a day; failures before a part runs (a missing
+// input or a parse error) have no part
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Record {
+    pub year: u32,
+    pub day: u32,
+    pub part: Option<u8>,
+    pub answer: Option<String>,
+    pub input_hash: Option<String>,
+    pub duration_ns: Option<u64>,
+    pub error: Option<String>,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)?;
+        if let Some(part) = self.part {
+            write!(f, " part {}", part)?;
+        }
+
+        match (&self.answer, &self.error) {
+            (_, Some(error)) if self.part.is_some() => write!(f, " error: {}", error),
+            (_, Some(error)) => write!(f, " {}", error),
+            (Some(answer), None) => write!(f, ": {}", answer),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+// hex sha256 of the input, so records can be grouped by the input they ran on
+pub fn input_hash(contents: &[u8]) -> String {
+    Sha256::digest(contents)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+#[derive(Debug)]
+pub enum Error {
+    IO(io::Error),
+    Json(serde_json::Error),
+    Csv(csv::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::IO(e) => write!(f, "{}", e),
+            Error::Json(e) => write!(f, "{}", e),
+            Error::Csv(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+// writes records as they are produced, flushing each one so scripts reading
+// the output see results as soon as a day finishes
+pub enum Report<W: Write> {
+    Text(W),
+    Json(W),
+    Csv(Box<csv::Writer<W>>),
+}
+
+impl<W: Write> Report<W> {
+    pub fn new(format: Format, out: W) -> Report<W> {
+        match format {
+            Format::Text => Report::Text(out),
+            Format::Json => Report::Json(out),
+            Format::Csv => Report::Csv(Box::new(csv::Writer::from_writer(out))),
+        }
+    }
+
+    pub fn write(&mut self, record: &Record) -> Result<(), Error> {
+        match self {
+            Report::Text(out) => {
+                writeln!(out, "{}", record).map_err(Error::IO)?;
+                out.flush().map_err(Error::IO)
+            }
+            Report::Json(out) => {
+                serde_json::to_writer(&mut *out, record).map_err(Error::Json)?;
+                writeln!(out).map_err(Error::IO)?;
+                out.flush().map_err(Error::IO)
+            }
+            Report::Csv(writer) => {
+                writer.serialize(record).map_err(Error::Csv)?;
+                writer.flush().map_err(Error::IO)
+            }
+        }
+    }
+
+    // flushes anything still buffered and hands back the writer
+    pub fn finish(self) -> Result<W, Error> {
+        match self {
+            Report::Text(out) | Report::Json(out) => Ok(out),
+            Report::Csv(writer) => writer.into_inner().map_err(|e| Error::IO(e.into_error())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{input_hash, Format, Record, Report};
+
+    fn records() -> Vec<Record> {
+        vec![
+            Record {
+                year: 2020,
+                day: 1,
+                part: Some(1),
+                answer: Some("514579".to_string()),
+                input_hash: Some(input_hash(b"")),
+                duration_ns: Some(1500),
+                error: None,
+            },
+            Record {
+                year: 2020,
+                day: 2,
+                part: None,
+                answer: None,
+                input_hash: None,
+                duration_ns: None,
+                error: Some("input error: no input".to_string()),
+            },
+        ]
+    }
+
+    fn output(format: Format) -> String {
+        let mut report = Report::new(format, Vec::new());
+        for record in records() {
+            report.write(&record).unwrap();
+        }
+        String::from_utf8(report.finish().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_formats() {
+        let empty = input_hash(b"");
+
+        assert_eq!(
+            output(Format::Text),
+            "2020 day 1 part 1: 514579\n2020 day 2 input error: no input\n"
+        );
+
+        assert_eq!(
+            output(Format::Json),
+            format!(
+                "{{\"year\":2020,\"day\":1,\"part\":1,\"answer\":\"514579\",\"input_hash\":\"{}\",\"duration_ns\":1500,\"error\":null}}\n\
+                 {{\"year\":2020,\"day\":2,\"part\":null,\"answer\":null,\"input_hash\":null,\"duration_ns\":null,\"error\":\"input error: no input\"}}\n",
+                empty
+            )
+        );
+
+        assert_eq!(
+            output(Format::Csv),
+            format!(
+                "year,day,part,answer,input_hash,duration_ns,error\n\
+                 2020,1,1,514579,{},1500,\n\
+                 2020,2,,,,,input error: no input\n",
+                empty
+            )
+        );
+    }
+}
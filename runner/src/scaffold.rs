@@ -0,0 +1,333 @@
+use crate::answers::Answers;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_RS: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_RS: &str = include_str!("../templates/main.rs.tmpl");
+const MODULE_RS: &str = include_str!("../templates/module.rs.tmpl");
+
+// 2019 keeps every day as a module of one crate, later years use a crate per day
+const MODULE_YEAR: u32 = 2019;
+
+fn render(template: &str, year: u32, day: u32) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day02}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+// inserts `line` before the first line whose key sorts after `key`, or after
+// the last keyed line if there is none
+fn insert_sorted(
+    text: &str,
+    line: &str,
+    key: (u32, u32),
+    key_of: impl Fn(&str) -> Option<(u32, u32)>,
+) -> Result<String, String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let keyed: Vec<(usize, (u32, u32))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, l)| key_of(l).map(|k| (idx, k)))
+        .collect();
+
+    if keyed.iter().any(|&(_, k)| k == key) {
+        return Err(format!("{:?} is already present", line.trim()));
+    }
+
+    let position = match keyed.iter().find(|&&(_, k)| k > key) {
+        Some(&(idx, _)) => idx,
+        None => match keyed.last() {
+            Some(&(idx, _)) => idx + 1,
+            None => return Err(format!("nowhere to insert {:?}", line.trim())),
+        },
+    };
+
+    let mut updated: Vec<&str> = lines[..position].to_vec();
+    updated.push(line);
+    updated.extend_from_slice(&lines[position..]);
+
+    Ok(updated.join("\n") + "\n")
+}
+
+fn parse_key(year: &str, day: &str) -> Option<(u32, u32)> {
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+// `    entry::<aoc2020_day1::Day1>(2020, 1),`
+fn registry_key(line: &str) -> Option<(u32, u32)> {
+    let args = line.trim().strip_prefix("entry::<")?.split_once(">(")?.1;
+    let (year, day) = args.strip_suffix("),")?.split_once(", ")?;
+    parse_key(year, day)
+}
+
+// `aoc2020-day1 = { path = "../2020/day1" }` or `aoc2019 = { path = "../2019/aoc" }`
+fn dependency_key(line: &str) -> Option<(u32, u32)> {
+    let name = line.strip_prefix("aoc")?.split_once(" = ")?.0;
+    match name.split_once("-day") {
+        Some((year, day)) => parse_key(year, day),
+        None => parse_key(name, "0"),
+    }
+}
+
+// `    "2020/day*",`
+fn member_key(line: &str) -> Option<(u32, u32)> {
+    let year = line.trim().strip_prefix('"')?.strip_suffix("/day*\",")?;
+    parse_key(year, "0")
+}
+
+// `pub mod day01;`
+fn module_key(line: &str) -> Option<(u32, u32)> {
+    let day = line.strip_prefix("pub mod day")?.strip_suffix(';')?;
+    parse_key(&MODULE_YEAR.to_string(), day)
+}
+
+// adds the module to the `use aoc2019::{...}` list and runs it at the end of main
+fn add_to_module_main(main_rs: &str, day: u32) -> Result<String, String> {
+    let module = format!("day{:02}", day);
+    let (head, rest) = main_rs
+        .split_once("use aoc2019::{")
+        .ok_or("cannot find the aoc2019 imports")?;
+    let (modules, rest) = rest
+        .split_once("};")
+        .ok_or("cannot find the aoc2019 imports")?;
+
+    let mut modules: Vec<&str> = modules.split(',').map(|m| m.trim()).collect();
+    modules.push(&module);
+    modules.sort();
+
+    let body = rest
+        .trim_end()
+        .strip_suffix('}')
+        .ok_or("cannot find the end of main")?;
+
+    Ok(format!(
+        "{}use aoc2019::{{{}}};{}\n\n    println!(\"{}\");\n    solution::run_day::<{}::Day{:02}>({}, {});\n}}\n",
+        head,
+        modules.join(", "),
+        body.trim_end(),
+        module,
+        module,
+        day,
+        MODULE_YEAR,
+        day
+    ))
+}
+
+fn edited(
+    path: &Path,
+    edit: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<String, String> {
+    let contents = fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    edit(&contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn create(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("{}: {}", parent.display(), e))?;
+    }
+    fs::write(path, contents).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+// generates the day and wires it into the workspace, runner, registry and
+// answers file, returning the files it created or changed
+pub fn new_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let registry = root.join("runner/src/registry.rs");
+    let entry = if year == MODULE_YEAR {
+        format!(
+            "    entry::<aoc2019::day{:02}::Day{:02}>({}, {}),",
+            day, day, year, day
+        )
+    } else {
+        format!(
+            "    entry::<aoc{}_day{}::Day{}>({}, {}),",
+            year, day, day, year, day
+        )
+    };
+
+    // work out the contents of every file before writing any of them, so a
+    // file that can't be read or edited leaves the tree as it was
+    let mut files = vec![(
+        registry.clone(),
+        edited(&registry, |text| {
+            insert_sorted(text, &entry, (year, day), registry_key)
+        })?,
+    )];
+    // a new crate's files are reported as its directory
+    let mut created = Vec::new();
+    let mut changed = Vec::new();
+
+    if year == MODULE_YEAR {
+        let dir = root.join("2019/aoc");
+        let module = dir.join(format!("src/day{:02}.rs", day));
+        if module.exists() {
+            return Err(format!("{} already exists", module.display()));
+        }
+
+        let lib_rs = dir.join("src/lib.rs");
+        let main_rs = dir.join("src/main.rs");
+        files.extend([
+            (module, render(MODULE_RS, year, day)),
+            (dir.join(format!("day{:02}_test.txt", day)), String::new()),
+            (
+                lib_rs.clone(),
+                edited(&lib_rs, |text| {
+                    insert_sorted(
+                        text,
+                        &format!("pub mod day{:02};", day),
+                        (year, day),
+                        module_key,
+                    )
+                })?,
+            ),
+            (
+                main_rs.clone(),
+                edited(&main_rs, |text| add_to_module_main(text, day))?,
+            ),
+        ]);
+    } else {
+        let dir = root.join(format!("{}/day{}", year, day));
+        if dir.exists() {
+            return Err(format!("{} already exists", dir.display()));
+        }
+
+        created.extend([
+            (dir.join("Cargo.toml"), render(CARGO_TOML, year, day)),
+            (dir.join("src/lib.rs"), render(LIB_RS, year, day)),
+            (dir.join("src/main.rs"), render(MAIN_RS, year, day)),
+            (dir.join("1_test.txt"), String::new()),
+        ]);
+        changed.push(dir);
+
+        let members = root.join("Cargo.toml");
+        let workspace = fs::read_to_string(&members).map_err(|e| e.to_string())?;
+        let member = format!("    \"{}/day*\",", year);
+        if !workspace.contains(member.trim()) {
+            let workspace = insert_sorted(&workspace, &member, (year, 0), member_key)
+                .map_err(|e| format!("{}: {}", members.display(), e))?;
+            files.push((members, workspace));
+        }
+
+        let dependency = format!(
+            "aoc{}-day{} = {{ path = \"../{}/day{}\" }}",
+            year, day, year, day
+        );
+        let runner_toml = root.join("runner/Cargo.toml");
+        files.push((
+            runner_toml.clone(),
+            edited(&runner_toml, |text| {
+                insert_sorted(text, &dependency, (year, day), dependency_key)
+            })?,
+        ));
+    }
+
+    let answers_path = root.join("answers.toml");
+    let mut answers = Answers::read(&answers_path).map_err(|e| e.to_string())?;
+    answers.add_day(year, day);
+    files.push((answers_path, answers.to_toml().map_err(|e| e.to_string())?));
+
+    for (path, contents) in created {
+        create(&path, &contents)?;
+    }
+    for (path, contents) in files {
+        create(&path, &contents)?;
+        changed.push(path);
+    }
+
+    Ok(changed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_to_module_main, dependency_key, insert_sorted, new_day, registry_key, render, LIB_RS,
+    };
+    use std::fs;
+
+    #[test]
+    fn test_render() {
+        let lib_rs = render(LIB_RS, 2022, 7);
+        assert!(lib_rs.contains("pub struct Day7;"));
+        assert!(!lib_rs.contains("{{"));
+    }
+
+    #[test]
+    fn test_insert_registry_entry() {
+        let registry = "pub const DAYS: &[Day] = &[\n    entry::<aoc2019::day01::Day01>(2019, 1),\n    entry::<aoc2020_day9::Day9>(2020, 9),\n    entry::<aoc2020_day10::Day10>(2020, 10),\n];\n";
+
+        let updated = insert_sorted(
+            registry,
+            "    entry::<aoc2020_day2::Day2>(2020, 2),",
+            (2020, 2),
+            registry_key,
+        )
+        .unwrap();
+        assert_eq!(
+            updated,
+            "pub const DAYS: &[Day] = &[\n    entry::<aoc2019::day01::Day01>(2019, 1),\n    entry::<aoc2020_day2::Day2>(2020, 2),\n    entry::<aoc2020_day9::Day9>(2020, 9),\n    entry::<aoc2020_day10::Day10>(2020, 10),\n];\n"
+        );
+
+        let appended = insert_sorted(
+            registry,
+            "    entry::<aoc2022_day1::Day1>(2022, 1),",
+            (2022, 1),
+            registry_key,
+        )
+        .unwrap();
+        assert!(appended.ends_with("(2020, 10),\n    entry::<aoc2022_day1::Day1>(2022, 1),\n];\n"));
+
+        assert!(insert_sorted(registry, "", (2020, 9), registry_key).is_err());
+    }
+
+    #[test]
+    fn test_dependency_key() {
+        assert_eq!(
+            dependency_key("aoc2020-day10 = { path = \"../2020/day10\" }"),
+            Some((2020, 10))
+        );
+        assert_eq!(
+            dependency_key("aoc2019 = { path = \"../2019/aoc\" }"),
+            Some((2019, 0))
+        );
+        assert_eq!(
+            dependency_key("aoc-common = { path = \"../common\" }"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_add_to_module_main() {
+        let main_rs = "use aoc2019::{day01, day02};\nuse aoc_common::solution;\n\nfn main() {\n    println!(\"day01\");\n    solution::run_day::<day01::Day01>(2019, 1);\n}\n";
+
+        assert_eq!(
+            add_to_module_main(main_rs, 5).unwrap(),
+            "use aoc2019::{day01, day02, day05};\nuse aoc_common::solution;\n\nfn main() {\n    println!(\"day01\");\n    solution::run_day::<day01::Day01>(2019, 1);\n\n    println!(\"day05\");\n    solution::run_day::<day05::Day05>(2019, 5);\n}\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day_leaves_tree_on_error() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("runner/src")).unwrap();
+        let registry =
+            "pub const DAYS: &[Day] = &[\n    entry::<aoc2020_day1::Day1>(2020, 1),\n];\n";
+        fs::write(root.join("runner/src/registry.rs"), registry).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"2020/day*\",\n]\n",
+        )
+        .unwrap();
+        // the runner has nowhere to add the dependency
+        fs::write(root.join("runner/Cargo.toml"), "[package]\n").unwrap();
+
+        let result = new_day(&root, 2022, 1);
+        let unchanged = fs::read_to_string(root.join("runner/src/registry.rs")).unwrap();
+        let created = root.join("2022/day1").exists();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(result.is_err());
+        assert_eq!(unchanged, registry);
+        assert!(!created);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::answers::Answers;
+use crate::report::Record;
+use std::fmt;
+use std::time::Duration;
+
+// answers longer than this are cut short in the table
+const ANSWER_WIDTH: usize = 24;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail(String),
+    Unrecorded,
+    Error,
+}
+
+impl Status {
+    pub fn check(record: &Record, answers: &Answers) -> Status {
+        let (Some(part), Some(answer)) = (record.part, &record.answer) else {
+            return Status::Error;
+        };
+
+        match answers.get(record.year, record.day, part) {
+            Some(expected) if expected == answer => Status::Pass,
+            Some(expected) => Status::Fail(expected.to_string()),
+            None => Status::Unrecorded,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail(_) => "FAIL",
+            Status::Unrecorded => "unrecorded",
+            Status::Error => "ERROR",
+        }
+    }
+}
+
+// a single line of the table, multi-line answers such as letters drawn in
+// '#' are summarised by their size
+fn short(answer: &str) -> String {
+    let answer = answer.trim();
+    let lines = answer.lines().count();
+    if lines > 1 {
+        return format!("<{} lines>", lines);
+    }
+
+    if answer.chars().count() > ANSWER_WIDTH {
+        let cut: String = answer.chars().take(ANSWER_WIDTH - 3).collect();
+        return format!("{}...", cut);
+    }
+
+    answer.to_string()
+}
+
+#[derive(Debug, Default)]
+pub struct Summary {
+    rows: Vec<(Record, Status)>,
+}
+
+impl Summary {
+    pub fn add(&mut self, record: Record, answers: &Answers) {
+        let status = Status::check(&record, answers);
+        self.rows.push((record, status));
+    }
+
+    fn count(&self, name: &str) -> usize {
+        self.rows.iter().filter(|(_, s)| s.name() == name).count()
+    }
+
+    pub fn failed(&self) -> bool {
+        self.rows
+            .iter()
+            .any(|(_, s)| matches!(s, Status::Fail(_) | Status::Error))
+    }
+
+    // the time spent in the parts themselves, summed over every day
+    pub fn total(&self) -> Duration {
+        self.rows
+            .iter()
+            .filter_map(|(r, _)| r.duration_ns)
+            .map(Duration::from_nanos)
+            .sum()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<4} {:>3} {:>4}  {:<width$}  {:<10}  {:>10}",
+            "year",
+            "day",
+            "part",
+            "answer",
+            "status",
+            "time",
+            width = ANSWER_WIDTH
+        )?;
+
+        for (record, status) in &self.rows {
+            let part = record.part.map(|p| p.to_string()).unwrap_or_default();
+            let time = record
+                .duration_ns
+                .map(|ns| format!("{:.2?}", Duration::from_nanos(ns)))
+                .unwrap_or_default();
+            writeln!(
+                f,
+                "{:<4} {:>3} {:>4}  {:<width$}  {:<10}  {:>10}",
+                record.year,
+                record.day,
+                part,
+                record.answer.as_deref().map(short).unwrap_or_default(),
+                status.name(),
+                time,
+                width = ANSWER_WIDTH
+            )?;
+        }
+
+        // the details of anything that went wrong, below the table
+        let mut problems = self
+            .rows
+            .iter()
+            .filter_map(|(record, status)| match status {
+                Status::Fail(expected) => Some(format!(
+                    "{} day {} part {}: expected {}, got {}",
+                    record.year,
+                    record.day,
+                    record.part.unwrap_or_default(),
+                    short(expected),
+                    record.answer.as_deref().map(short).unwrap_or_default()
+                )),
+                Status::Error => Some(record.to_string()),
+                _ => None,
+            });
+
+        if let Some(first) = problems.next() {
+            writeln!(f, "\n{}", first)?;
+            for problem in problems {
+                writeln!(f, "{}", problem)?;
+            }
+        }
+
+        write!(
+            f,
+            "\n{} passed, {} failed, {} errors, {} unrecorded in {:.2?}",
+            self.count("pass"),
+            self.count("FAIL"),
+            self.count("ERROR"),
+            self.count("unrecorded"),
+            self.total()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{short, Status, Summary};
+    use crate::answers::Answers;
+    use crate::report::Record;
+
+    fn record(day: u32, part: Option<u8>, answer: Option<&str>) -> Record {
+        Record {
+            year: 2020,
+            day,
+            part,
+            answer: answer.map(str::to_string),
+            input_hash: None,
+            duration_ns: Some(2_000_000),
+            error: match answer {
+                Some(_) => None,
+                None => Some("parse error: bad".to_string()),
+            },
+        }
+    }
+
+    #[test]
+    fn test_status() {
+        let mut answers = Answers::default();
+        answers.set(2020, 1, 1, "42".to_string());
+
+        let check = |r: &Record| Status::check(r, &answers);
+        assert_eq!(check(&record(1, Some(1), Some("42"))), Status::Pass);
+        assert_eq!(
+            check(&record(1, Some(1), Some("41"))),
+            Status::Fail("42".to_string())
+        );
+        assert_eq!(check(&record(1, Some(2), Some("7"))), Status::Unrecorded);
+        assert_eq!(check(&record(1, None, None)), Status::Error);
+    }
+
+    #[test]
+    fn test_short() {
+        assert_eq!(short("1234"), "1234");
+        assert_eq!(short("\n#..\n.#.\n"), "<2 lines>");
+        assert_eq!(short(&"9".repeat(30)), format!("{}...", "9".repeat(21)));
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut answers = Answers::default();
+        answers.set(2020, 1, 1, "42".to_string());
+        answers.set(2020, 1, 2, "43".to_string());
+
+        let mut summary = Summary::default();
+        summary.add(record(1, Some(1), Some("42")), &answers);
+        summary.add(record(1, Some(2), Some("44")), &answers);
+        summary.add(record(2, None, None), &answers);
+        assert!(summary.failed());
+
+        let table = summary.to_string();
+        assert!(table.contains("expected 43, got 44"));
+        assert!(table.contains("2020 day 2 parse error: bad"));
+        assert!(table.ends_with("1 passed, 1 failed, 1 errors, 0 unrecorded in 6.00ms"));
+    }
+}